@@ -1,18 +1,62 @@
-const protocol = location.protocol.startsWith("https") ? "wss" : "ws";
-const wsUri = `${protocol}://${location.hostname}:4421/ws`;
+const apiUri = `${location.protocol}//${location.hostname}:4421/admin`;
 
-let socket = new WebSocket(wsUri);
+const token = document.getElementById("token");
+const lobby = document.getElementById("lobby");
+const lobbies = document.getElementById("lobbies");
 
-let time = document.getElementById("time");
-
-socket.addEventListener("open", (_event) => {
-  console.log("connected");
-  document.getElementById("start").addEventListener("click", (ev) => {
-    ev.preventDefault();
-    socket.send(`vote_start_game`);
+async function request(method, path) {
+  const response = await fetch(`${apiUri}${path}`, {
+    method,
+    headers: { Authorization: `Bearer ${token.value}` },
   });
+  if (!response.ok) {
+    console.log(`${method} ${path} failed: ${await response.text()}`);
+    return null;
+  }
+  return response.status === 204 ? null : response.json();
+}
+
+async function refresh() {
+  const status = (await request("GET", "/lobbies")) ?? [];
+  lobbies.replaceChildren(
+    ...status.map((lobbyStatus) => {
+      const item = document.createElement("li");
+      item.textContent = `${lobbyStatus.code} ${lobbyStatus.phase} (${
+        lobbyStatus.votes
+      }/${lobbyStatus.required_votes} votes)`;
+
+      const ducks = document.createElement("ul");
+      for (const duck of lobbyStatus.ducks) {
+        const duckItem = document.createElement("li");
+        duckItem.textContent = `${duck.id} ${duck.name} ${duck.score} `;
+        const kick = document.createElement("button");
+        kick.textContent = "kick";
+        kick.addEventListener("click", async () => {
+          await request("POST", `/kick/${duck.id}?lobby=${lobbyStatus.code}`);
+          refresh();
+        });
+        duckItem.append(kick);
+        ducks.append(duckItem);
+      }
+      item.append(ducks);
+      return item;
+    }),
+  );
+}
+
+document.getElementById("start").addEventListener("click", async (ev) => {
+  ev.preventDefault();
+  await request("POST", `/start?lobby=${lobby.value}`);
+  refresh();
+});
+
+document.getElementById("end").addEventListener("click", async (ev) => {
+  ev.preventDefault();
+  await request("POST", `/end?lobby=${lobby.value}`);
+  refresh();
 });
 
-socket.addEventListener("error", (_event) => {
-  console.log("socket error");
+document.getElementById("refresh").addEventListener("click", (ev) => {
+  ev.preventDefault();
+  refresh();
 });
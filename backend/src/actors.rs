@@ -1,7 +1,13 @@
 //! Actors
 
 mod game_server;
+mod lobby_manager;
 mod player;
+mod replay_player;
+mod storage;
 
 pub use game_server::GameServer;
+pub use lobby_manager::{normalize_lobby_code, LobbyManager, DEFAULT_LOBBY_CODE};
 pub use player::Player;
+pub use replay_player::ReplayPlayer;
+pub use storage::{LeaderboardEntry, RoundParticipant, RoundRecord, Storage};
@@ -1,55 +1,347 @@
-use crate::{actors, duck::Duck, messages, protos::protos::protos};
+use crate::{
+    actors,
+    bread::{Bread, BreadKind},
+    collision::{DuckCollision, BUMP_STEAL_POINTS, CONTACT_TOLERANCE, DUCK_HEIGHT, DUCK_RADIUS},
+    config::Config,
+    duck::{Duck, POND_RADIUS},
+    error::GameError,
+    id_allocator::IdAllocator,
+    messages,
+    phase::Phase,
+    power_up::{shield_push_share, PowerUp, PowerUpKind, MAGNET_PULL_SPEED, MAGNET_RADIUS},
+    protos::protos::protos::{self, replay_event},
+    replay::ReplayRecorder,
+    settings::GameSettings,
+    snapshot::{QuantizedDuck, SnapshotHistory},
+};
 use protobuf::{Message, SpecialFields};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{hash_map::Entry, BTreeMap, HashMap, HashSet, VecDeque},
     f32::consts::PI,
-    time::{Duration, SystemTime},
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
 };
 
-const UPDATE_SYNC_INTERVAL: Duration = Duration::from_millis(50);
-const BREAD_SPAWN_PER_SECOND: f32 = 3.0;
-const BREAD_LIMIT: usize = 500;
+/// Most ticks simulated at once after the actor stalls, dropping the rest
+const MAX_CATCH_UP_TICKS: u32 = 10;
+/// How often players that stopped without leaving are looked for
+const PLAYER_REAP_INTERVAL: Duration = Duration::from_secs(5);
+/// Half extents of the boxes used for pickups
+const DUCK_SIZE: Vec3 = (0.5, 0.5, 0.5);
+const POWER_UP_SIZE: Vec3 = (0.3, 0.3, 0.3);
+
+type Vec3 = (f32, f32, f32);
+
+/// Returns whether two axis aligned boxes, given by centers and half extents, overlap
+fn intersect(a: &Vec3, b: &Vec3, a_size: &Vec3, b_size: &Vec3) -> bool {
+    a.0 - a_size.0 <= b.0 + b_size.0
+        && a.0 + a_size.0 >= b.0 - b_size.0
+        && a.1 - a_size.1 <= b.1 + b_size.1
+        && a.1 + a_size.1 >= b.1 - b_size.1
+        && a.2 - a_size.2 <= b.2 + b_size.2
+        && a.2 + a_size.2 >= b.2 - b_size.2
+}
 
 use actix::prelude::*;
-use rand::{rngs::ThreadRng, Rng};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 
 /// A game server actor
 ///
 /// Contains state of all player actor addresses, and game state
 ///
 /// Handles updating world state and communicates with `Player` actor
+///
+/// Each lobby runs its own `GameServer`, started and reaped by `LobbyManager`
 #[derive(Debug)]
 pub struct GameServer {
+    pub lobby_code: String,
+    pub lobby_manager_address: Addr<actors::LobbyManager>,
+    pub storage_address: Addr<actors::Storage>,
+    pub empty_since: Option<Instant>,
     pub player_actors: HashMap<u32, Addr<actors::player::Player>>,
     pub ducks: HashMap<u32, Duck>,
-    pub _spectator_ids: HashSet<u32>,
-    pub bread_list: Vec<(f32, f32, f32)>,
+    pub duck_ids: IdAllocator,
+    pub resume_tokens: HashMap<String, u32>,
+    pub disconnected_ducks: HashMap<u32, Instant>,
+    pub spectator_ids: HashSet<u32>,
+    pub start_votes: HashSet<u32>,
+    /// Duck allowed to start rounds, change settings and kick ducks, the first to join
+    pub host_id: Option<u32>,
+    pub snapshots: SnapshotHistory,
+    pub acked_snapshots: HashMap<u32, u32>,
+    pub config: Arc<Config>,
+    pub bread: BTreeMap<u32, Bread>,
+    pub next_bread_id: u32,
+    pub spawned_bread_ids: Vec<u32>,
+    /// Bread pulled by a magnet since the last update
+    pub moved_bread_ids: HashSet<u32>,
+    /// Spawned bread not yet sent to legacy clients, which get one bread per update
+    pub legacy_bread_queue: VecDeque<u32>,
+    pub bread_pickups: Vec<(u32, u32)>,
+    pub bread_resync_ids: HashSet<u32>,
+    pub round_bread_spawned: usize,
+    pub power_ups: BTreeMap<u32, PowerUp>,
+    pub next_power_up_id: u32,
+    /// Pairs of ducks touching as of the last tick, lower id first
+    pub touching_ducks: HashSet<(u32, u32)>,
+    /// Position of each duck on the water as of the last tick
+    pub last_duck_positions: HashMap<u32, (f32, f32)>,
+    pub duck_collisions: Vec<DuckCollision>,
+    pub phase: Phase,
+    /// Tick the current phase started at
+    pub phase_tick: u64,
+    /// Start of the running round, set only while playing
     pub start_time: Option<std::time::SystemTime>,
-    pub current_time: std::time::SystemTime,
-    pub game_duration: Duration,
-    pub rng: ThreadRng,
+    pub tick: u64,
+    pub last_tick_time: Instant,
+    pub tick_accumulator: Duration,
+    pub settings: GameSettings,
+    pub rng: ChaCha8Rng,
+    pub seed: u64,
+    pub replay: Option<ReplayRecorder>,
 }
 
 impl GameServer {
-    pub fn new() -> GameServer {
+    pub fn new(
+        lobby_code: String,
+        lobby_manager_address: Addr<actors::LobbyManager>,
+        storage_address: Addr<actors::Storage>,
+        config: Arc<Config>,
+    ) -> GameServer {
         GameServer {
+            lobby_code,
+            lobby_manager_address,
+            storage_address,
+            empty_since: None,
             player_actors: HashMap::new(),
-            rng: rand::thread_rng(),
+            rng: ChaCha8Rng::seed_from_u64(rand::random()),
+            seed: 0,
+            replay: None,
             ducks: HashMap::new(),
-            _spectator_ids: HashSet::new(),
-            bread_list: Vec::new(),
+            duck_ids: IdAllocator::new(config.recycle_duck_ids),
+            resume_tokens: HashMap::new(),
+            disconnected_ducks: HashMap::new(),
+            spectator_ids: HashSet::new(),
+            start_votes: HashSet::new(),
+            host_id: None,
+            snapshots: SnapshotHistory::default(),
+            acked_snapshots: HashMap::new(),
+            bread: BTreeMap::new(),
+            next_bread_id: 1,
+            spawned_bread_ids: Vec::new(),
+            moved_bread_ids: HashSet::new(),
+            legacy_bread_queue: VecDeque::new(),
+            bread_pickups: Vec::new(),
+            bread_resync_ids: HashSet::new(),
+            round_bread_spawned: 0,
+            touching_ducks: HashSet::new(),
+            last_duck_positions: HashMap::new(),
+            duck_collisions: Vec::new(),
+            power_ups: BTreeMap::new(),
+            next_power_up_id: 1,
+            phase: Phase::Waiting,
+            phase_tick: 0,
             start_time: None,
-            current_time: SystemTime::now(),
-            game_duration: Duration::from_secs(30),
+            tick: 0,
+            last_tick_time: Instant::now(),
+            tick_accumulator: Duration::ZERO,
+            settings: GameSettings::new(&config),
+            config,
+        }
+    }
+
+    /// Returns ids of all ducks in the lobby that aren't spectating
+    fn playing_duck_ids(&self) -> Vec<u32> {
+        self.ducks
+            .keys()
+            .filter(|id| !self.spectator_ids.contains(id))
+            .copied()
+            .collect()
+    }
+
+    /// Issues a new resume token for the duck, replacing any previous one
+    pub fn issue_resume_token(&mut self, id: u32) -> String {
+        self.resume_tokens.retain(|_, duck_id| *duck_id != id);
+        // tokens must be unpredictable, so they don't come from the seeded lobby rng
+        let token = format!("{:032x}", rand::thread_rng().gen::<u128>());
+        self.resume_tokens.insert(token.clone(), id);
+        token
+    }
+
+    /// Notifies a joining player of every other duck in the lobby
+    pub fn send_existing_ducks(&self, id: u32, player_address: &Addr<actors::Player>) {
+        self.ducks
+            .iter()
+            .filter(|(duck_id, _)| **duck_id != id)
+            .for_each(|(duck_id, duck)| {
+                player_address.do_send(messages::CastJoinGame {
+                    id: *duck_id,
+                    name: duck.name.clone().unwrap_or_default(),
+                    variety: duck.variety.clone().unwrap_or_default(),
+                    color: duck.color.clone().unwrap_or_default(),
+                    spectator: self.spectator_ids.contains(duck_id),
+                    host: self.host_id == Some(*duck_id),
+                    team: duck.team,
+                });
+            });
+    }
+
+    /// Picks the team of a duck joining in team mode
+    ///
+    /// The requested team is kept unless it already has more ducks than the smallest
+    /// team, otherwise the duck is balanced into the smallest team
+    pub fn assign_team(&self, requested: Option<u32>) -> Option<u32> {
+        let mut sizes = vec![0; self.config.team_count as usize];
+        for team in self.ducks.values().filter_map(|duck| duck.team) {
+            sizes[team as usize] += 1;
+        }
+        let smallest = *sizes.iter().min()?;
+        match requested {
+            Some(team) if sizes.get(team as usize) == Some(&smallest) => Some(team),
+            _ => sizes
+                .iter()
+                .position(|size| *size == smallest)
+                .map(|team| team as u32),
         }
     }
 
+    /// Returns total score of each team's playing ducks in team order, empty without team mode
+    fn team_scores(&self) -> Vec<u32> {
+        let mut scores = vec![0; self.config.team_count as usize];
+        for id in self.playing_duck_ids() {
+            let duck = &self.ducks[&id];
+            if let Some(team) = duck.team {
+                scores[team as usize] += duck.score;
+            }
+        }
+        scores
+    }
+
+    /// Checks a message is from the host, or from an admin if no duck is given
+    pub fn check_host(&self, requester: Option<u32>) -> Result<(), GameError> {
+        match requester {
+            Some(id) if self.host_id != Some(id) => Err(GameError::NotHost),
+            _ => Ok(()),
+        }
+    }
+
+    /// Hands the host role to the connected duck with the lowest id if the host left
+    ///
+    /// Everyone is told the new host, there's no host while no player is connected
+    pub fn transfer_host(&mut self) {
+        if self
+            .host_id
+            .is_some_and(|id| self.player_actors.contains_key(&id))
+        {
+            return;
+        }
+        self.host_id = self.player_actors.keys().min().copied();
+        if let Some(id) = self.host_id {
+            log::info!("DUCK {id} IS NOW HOST OF LOBBY {}", self.lobby_code);
+            self.player_actors.iter().for_each(|(_, player)| {
+                player.do_send(messages::CastHost { id });
+            });
+        }
+    }
+
+    /// Keeps a duck whose player disconnected until the resume grace period ends
+    pub fn disconnect_duck(&mut self, id: u32, context: &mut Context<Self>) {
+        log::info!("DUCK {id} DISCONNECTED, WAITING FOR RESUME");
+        self.player_actors.remove(&id);
+        // a resuming client starts without any snapshots
+        self.acked_snapshots.remove(&id);
+        self.disconnected_ducks.insert(id, Instant::now());
+        self.transfer_host();
+        match self.phase {
+            Phase::Waiting => self.reset_votes(),
+            Phase::Countdown | Phase::Playing => self.end_game_if_players_left(),
+            Phase::Podium => {}
+        }
+
+        let grace_period = self.config.resume_grace_period();
+        context.run_later(grace_period, move |server_actor, context| {
+            let expired = server_actor
+                .disconnected_ducks
+                .get(&id)
+                .is_some_and(|disconnected_at| disconnected_at.elapsed() >= grace_period);
+            if expired {
+                server_actor.remove_duck(id, context);
+            }
+        });
+    }
+
+    /// Disconnects ducks whose `Player` stopped without sending `LeaveGame`
+    ///
+    /// Players only learn their lobby once their join is handled, so a player
+    /// stopping before then can't tell the lobby it left
+    fn reap_stopped_players(&mut self, context: &mut Context<Self>) {
+        let stopped_ids: Vec<u32> = self
+            .player_actors
+            .iter()
+            .filter(|(_, player)| !player.connected())
+            .map(|(id, _)| *id)
+            .collect();
+        for id in stopped_ids {
+            self.disconnect_duck(id, context);
+        }
+    }
+
+    /// Removes a duck from the lobby for good and notifies remaining players
+    pub fn remove_duck(&mut self, id: u32, context: &mut Context<Self>) {
+        log::info!("DUCK {id} LEFT");
+        self.player_actors.remove(&id);
+        self.ducks.remove(&id);
+        self.spectator_ids.remove(&id);
+        self.disconnected_ducks.remove(&id);
+        self.acked_snapshots.remove(&id);
+        self.bread_resync_ids.remove(&id);
+        self.resume_tokens.retain(|_, duck_id| *duck_id != id);
+        self.duck_ids.release(id);
+
+        self.player_actors.iter().for_each(|(_, actor)| {
+            actor.do_send(messages::CastLeaveGame { id });
+        });
+        self.record(replay_event::Event::LeaveGame(protos::LeaveGameCast {
+            id,
+            ..Default::default()
+        }));
+        self.transfer_host();
+        match self.phase {
+            Phase::Waiting => self.reset_votes(),
+            Phase::Countdown | Phase::Playing => self.end_game_if_players_left(),
+            Phase::Podium => {}
+        }
+
+        if self.ducks.is_empty() {
+            self.schedule_close(context);
+        }
+    }
+
+    /// Ends the running round or countdown early if no connected player is left in it
+    fn end_game_if_players_left(&mut self) {
+        let players_left = self
+            .player_actors
+            .keys()
+            .any(|id| !self.spectator_ids.contains(id));
+        if players_left {
+            return;
+        }
+        log::info!("ALL PLAYERS LEFT LOBBY {}", self.lobby_code);
+        if self.phase == Phase::Countdown {
+            self.cancel_countdown();
+            return;
+        }
+        self.end_game(messages::EndReason::PlayersLeft);
+    }
+
     /// Produces UpdateSync proto for the given lobby
     fn get_update_sync_proto(&mut self) -> protos::UpdateSync {
         let mut message = protos::UpdateSync::new();
         message.ducks = self
             .ducks
             .iter()
+            .filter(|(id, _)| !self.spectator_ids.contains(id))
             .map(|(id, duck)| protos::Duck {
                 id: *id,
                 rotation: duck.rotation_radians,
@@ -57,9 +349,49 @@ impl GameServer {
                 y: duck.y,
                 z: duck.z,
                 score: duck.score,
+                input_sequence: duck.input_sequence,
                 special_fields: SpecialFields::new(),
             })
             .collect();
+        message.team_scores = self
+            .team_scores()
+            .into_iter()
+            .enumerate()
+            .map(|(team, score)| protos::TeamScore {
+                team: team as u32,
+                score,
+                ..Default::default()
+            })
+            .collect();
+        message.power_ups = self
+            .power_ups
+            .iter()
+            .map(|(id, power_up)| power_up.to_proto(*id))
+            .collect();
+        message.duck_effects = self
+            .ducks
+            .iter()
+            .flat_map(|(id, duck)| {
+                duck.effects
+                    .iter()
+                    .map(|(kind, remaining)| protos::DuckEffect {
+                        duck_id: *id,
+                        kind: kind.to_proto().into(),
+                        remaining_ms: remaining.as_millis() as u32,
+                        ..Default::default()
+                    })
+            })
+            .collect();
+        message.slowed_ducks = self
+            .ducks
+            .iter()
+            .filter(|(_, duck)| !duck.slowed.is_zero())
+            .map(|(id, duck)| protos::SlowedDuck {
+                duck_id: *id,
+                remaining_ms: duck.slowed.as_millis() as u32,
+                ..Default::default()
+            })
+            .collect();
 
         message
     }
@@ -67,148 +399,827 @@ impl GameServer {
     /// Updates state of given lobby by one tick
     fn tick_game(&mut self, delta_time: f32) {
         // UPDATE BREAD
-        for (_, y, _) in &mut self.bread_list {
-            let gravity = -5.0;
-            // sqrt(v^2 - 2as) = u
-            let velocity = -f32::sqrt(f32::abs(2.0 * gravity * (10.0 - *y)));
-            *y += velocity * delta_time + 0.5 * gravity * delta_time.powi(2);
-            *y = y.max(0.1);
+        for bread in self.bread.values_mut() {
+            bread.fall(delta_time);
         }
 
-        let duck_ids: Vec<u32> = self.ducks.iter().map(|(id, _)| *id).collect();
+        let duck_ids: Vec<u32> = self.playing_duck_ids();
 
         // INTERSECTIONS
         for id in duck_ids {
             let duck = self.ducks.get_mut(&id).unwrap();
+            duck.tick_effects(Duration::from_secs_f32(delta_time));
+            if self.config.authoritative_movement {
+                duck.apply_input(delta_time);
+            }
+
             let duck_pos = &(duck.x, duck.y, duck.z);
 
-            let duck_size = &(0.5, 0.5, 0.5);
-            let bread_size = &(0.2, 0.2, 0.2);
-
-            let mut i = 0;
-            while i < self.bread_list.len() {
-                let bread_pos = self.bread_list.get(i).unwrap();
-
-                type Vec3 = (f32, f32, f32);
-                fn intersect(a: &Vec3, b: &Vec3, a_size: &Vec3, b_size: &Vec3) -> bool {
-                    return a.0 - a_size.0 <= b.0 + b_size.0
-                        && a.0 + a_size.0 >= b.0 - b_size.0
-                        && a.1 - a_size.1 <= b.1 + b_size.1
-                        && a.1 + a_size.1 >= b.1 - b_size.1
-                        && a.2 - a_size.2 <= b.2 + b_size.2
-                        && a.2 + a_size.2 >= b.2 - b_size.2;
+            self.power_ups.retain(|power_up_id, power_up| {
+                let power_up_pos = &(power_up.x, power_up.y, power_up.z);
+                let picked_up = intersect(duck_pos, power_up_pos, &DUCK_SIZE, &POWER_UP_SIZE);
+                if picked_up {
+                    log::info!(
+                        "DUCK {id} PICKED UP {} POWER-UP {power_up_id}",
+                        power_up.kind.to_string().to_uppercase()
+                    );
+                    duck.add_effect(power_up.kind);
+                }
+                !picked_up
+            });
+
+            if duck.has_effect(PowerUpKind::Magnet) {
+                for (bread_id, bread) in self.bread.iter_mut() {
+                    let (dx, dz) = (duck.x - bread.x, duck.z - bread.z);
+                    let distance = f32::hypot(dx, dz);
+                    if distance > 0.0 && distance < MAGNET_RADIUS {
+                        let pull = f32::min(MAGNET_PULL_SPEED * delta_time, distance);
+                        bread.x += dx / distance * pull;
+                        bread.z += dz / distance * pull;
+                        self.moved_bread_ids.insert(*bread_id);
+                    }
+                }
+            }
+
+            let multiplier = match duck.has_effect(PowerUpKind::DoublePoints) {
+                true => 2,
+                false => 1,
+            };
+            self.bread.retain(|bread_id, bread| {
+                let eaten = intersect(duck_pos, &bread.position(), &DUCK_SIZE, &bread.kind.size());
+                if eaten {
+                    duck.score += bread.kind.value() * multiplier;
+                    if bread.kind == BreadKind::Stale {
+                        duck.slow_down();
+                    }
+                    self.bread_pickups.push((*bread_id, id));
+                }
+                !eaten
+            });
+        }
+
+        // COLLISIONS
+        self.collide_ducks();
+    }
+
+    /// Detects ducks starting to touch, and pushes overlapping ducks apart with authoritative movement
+    ///
+    /// Client driven ducks are kept apart as their updates arrive instead, see `keep_clear_of_ducks`
+    ///
+    /// The duck that swam further into the other since the last tick is the bumper,
+    /// and steals points from the other during rounds if bumping steals bread.
+    /// Ducks with a shield aren't pushed or stolen from, and teammates don't steal
+    /// from each other
+    fn collide_ducks(&mut self) {
+        let mut duck_ids = self.playing_duck_ids();
+        duck_ids.sort_unstable();
+
+        let mut touching_ducks = HashSet::new();
+        for (i, &a) in duck_ids.iter().enumerate() {
+            for &b in &duck_ids[i + 1..] {
+                let (duck_a, duck_b) = (&self.ducks[&a], &self.ducks[&b]);
+                if (duck_a.y - duck_b.y).abs() >= DUCK_HEIGHT {
+                    continue;
+                }
+                let (dx, dz) = (duck_b.x - duck_a.x, duck_b.z - duck_a.z);
+                let distance = f32::hypot(dx, dz);
+                let overlap = DUCK_RADIUS * 2.0 - distance;
+                if overlap <= -CONTACT_TOLERANCE {
+                    continue;
+                }
+                touching_ducks.insert((a, b));
+
+                // direction from a to b, ducks on the same spot are split along x
+                let (nx, nz) = match distance > 0.0 {
+                    true => (dx / distance, dz / distance),
+                    false => (1.0, 0.0),
+                };
+                let shielded_a = duck_a.has_effect(PowerUpKind::Shield);
+                let shielded_b = duck_b.has_effect(PowerUpKind::Shield);
+                let teammates = duck_a.team.is_some() && duck_a.team == duck_b.team;
+
+                if !self.touching_ducks.contains(&(a, b)) {
+                    let approach = |id: u32, direction: f32| {
+                        let duck = &self.ducks[&id];
+                        let (last_x, last_z) = self
+                            .last_duck_positions
+                            .get(&id)
+                            .copied()
+                            .unwrap_or((duck.x, duck.z));
+                        ((duck.x - last_x) * nx + (duck.z - last_z) * nz) * direction
+                    };
+                    let (approach_a, approach_b) = (approach(a, 1.0), approach(b, -1.0));
+                    let bump = if approach_a > approach_b.max(0.0) {
+                        Some((a, b, shielded_b))
+                    } else if approach_b > approach_a.max(0.0) {
+                        Some((b, a, shielded_a))
+                    } else {
+                        None
+                    };
+
+                    let mut stolen_points = 0;
+                    if let Some((bumper_id, bumped_id, shielded)) = bump {
+                        if self.config.bump_steals_bread
+                            && self.phase == Phase::Playing
+                            && !shielded
+                            && !teammates
+                        {
+                            let bumped = self.ducks.get_mut(&bumped_id).unwrap();
+                            stolen_points = bumped.score.min(BUMP_STEAL_POINTS);
+                            bumped.score -= stolen_points;
+                            self.ducks.get_mut(&bumper_id).unwrap().score += stolen_points;
+                        }
+                        if stolen_points > 0 {
+                            log::info!(
+                                "DUCK {bumper_id} BUMPED DUCK {bumped_id}, STEALING {stolen_points} POINTS"
+                            );
+                        }
+                    }
+
+                    let duck_a = &self.ducks[&a];
+                    self.duck_collisions.push(DuckCollision {
+                        duck_ids: (a, b),
+                        x: duck_a.x + nx * (DUCK_RADIUS - overlap / 2.0),
+                        z: duck_a.z + nz * (DUCK_RADIUS - overlap / 2.0),
+                        bumper_id: bump.map(|(bumper_id, _, _)| bumper_id),
+                        stolen_points,
+                    });
                 }
 
-                if intersect(duck_pos, bread_pos, duck_size, bread_size) {
-                    self.bread_list.swap_remove(i);
-                    duck.score += 1;
-                } else {
-                    i += 1;
+                if self.config.authoritative_movement && overlap > 0.0 {
+                    let share_a = shield_push_share(shielded_a, shielded_b);
+                    let duck_a = self.ducks.get_mut(&a).unwrap();
+                    duck_a.x -= nx * overlap * share_a;
+                    duck_a.z -= nz * overlap * share_a;
+                    duck_a.keep_in_pond();
+                    let duck_b = self.ducks.get_mut(&b).unwrap();
+                    duck_b.x += nx * overlap * (1.0 - share_a);
+                    duck_b.z += nz * overlap * (1.0 - share_a);
+                    duck_b.keep_in_pond();
                 }
             }
         }
+
+        self.touching_ducks = touching_ducks;
+        self.last_duck_positions = duck_ids
+            .iter()
+            .map(|id| (*id, (self.ducks[id].x, self.ducks[id].z)))
+            .collect();
+    }
+
+    /// Moves a client driven duck out of any duck it swam into with its last update
+    ///
+    /// Only the updated duck is moved, since the other ducks' clients would move them
+    /// straight back and be flagged for moving too fast
+    pub fn keep_clear_of_ducks(&mut self, id: u32) {
+        for other_id in self.playing_duck_ids() {
+            if other_id == id {
+                continue;
+            }
+            let (duck, other) = (&self.ducks[&id], &self.ducks[&other_id]);
+            if (duck.y - other.y).abs() >= DUCK_HEIGHT {
+                continue;
+            }
+            let (dx, dz) = (duck.x - other.x, duck.z - other.z);
+            let distance = f32::hypot(dx, dz);
+            if distance >= DUCK_RADIUS * 2.0 {
+                continue;
+            }
+            // ducks on the same spot are split along x
+            let (nx, nz) = match distance > 0.0 {
+                true => (dx / distance, dz / distance),
+                false => (1.0, 0.0),
+            };
+            let (other_x, other_z) = (other.x, other.z);
+            let duck = self.ducks.get_mut(&id).unwrap();
+            duck.x = other_x + nx * DUCK_RADIUS * 2.0;
+            duck.z = other_z + nz * DUCK_RADIUS * 2.0;
+            duck.keep_in_pond();
+        }
     }
 
-    /// Appends new bread to lobby if it's started and past bread spawn interval
+    /// Adds new bread to lobby if it's started, at the configured rate per second on average
     ///
-    /// Returns the new bread coordinates if spawned, otherwise None
-    fn spawn_new_bread(&mut self) -> Option<(f32, f32, f32)> {
-        if self.start_time.is_none() {
-            return None;
+    /// Bread is limited per round when rounds end with all bread eaten, otherwise
+    /// the limit is on bread in the pond at once
+    fn spawn_new_bread(&mut self) {
+        if self.phase != Phase::Playing {
+            return;
         }
+        let bread_count = match self.config.end_when_all_bread_eaten {
+            true => self.round_bread_spawned,
+            false => self.bread.len(),
+        };
         if self.rng.gen_range(0.0..=1.0)
-            <= (BREAD_SPAWN_PER_SECOND * UPDATE_SYNC_INTERVAL.as_secs_f32())
-            && self.bread_list.len() < BREAD_LIMIT
+            <= (self.settings.bread_spawn_per_second * self.config.tick_interval().as_secs_f32())
+            && bread_count < self.settings.bread_limit
         {
-            let y = 10.0;
-
+            let kind = self.settings.bread_weights.pick(&mut self.rng);
             let theta = self.rng.gen_range(0.0..(PI * 2.0));
-            let r = self.rng.gen_range(0.0..11.5);
+            let r = self.rng.gen_range(0.0..POND_RADIUS);
 
             let x = f32::sin(theta) * r;
             let z = f32::cos(theta) * r;
 
-            self.bread_list.push((x, y, z));
+            let id = self.next_bread_id;
+            self.next_bread_id = self.next_bread_id.wrapping_add(1).max(1);
+            self.bread.insert(id, Bread::new(kind, x, z));
+            self.spawned_bread_ids.push(id);
+            if self.config.legacy_protocol {
+                self.legacy_bread_queue.push_back(id);
+            }
+            self.round_bread_spawned += 1;
+        }
+    }
 
-            return Some((x, y, z));
+    /// Adds a new power-up to the pond during rounds, at the configured rate per second on average
+    fn spawn_new_power_up(&mut self) {
+        if self.phase != Phase::Playing || self.power_ups.len() >= self.config.power_up_limit {
+            return;
+        }
+        if self.rng.gen_range(0.0..=1.0)
+            > self.config.power_up_spawn_per_second * self.config.tick_interval().as_secs_f32()
+        {
+            return;
         }
-        None
+
+        let kind = PowerUpKind::ALL[self.rng.gen_range(0..PowerUpKind::ALL.len())];
+        let theta = self.rng.gen_range(0.0..(PI * 2.0));
+        let r = self.rng.gen_range(0.0..POND_RADIUS);
+
+        let id = self.next_power_up_id;
+        self.next_power_up_id = self.next_power_up_id.wrapping_add(1).max(1);
+        self.power_ups.insert(
+            id,
+            PowerUp {
+                kind,
+                x: f32::sin(theta) * r,
+                y: 0.0,
+                z: f32::cos(theta) * r,
+            },
+        );
+    }
+
+    /// Returns every bread in the pond
+    fn bread_protos(&self) -> Vec<protos::Bread> {
+        self.bread
+            .iter()
+            .map(|(id, bread)| bread.to_proto(*id))
+            .collect()
     }
 
-    /// Sets lobby state to podium view
-    fn end_game(&mut self) {
-        let mut highest_scores = vec![(0, 0); usize::min(self.ducks.len(), 3)];
+    /// Writes bread spawned, moved and eaten since the last update to an UpdateSync
+    fn write_bread_events(&self, message: &mut protos::UpdateSync) {
+        // bread can be eaten before its spawn is broadcast
+        message.spawned_bread = self
+            .spawned_bread_ids
+            .iter()
+            .filter_map(|id| Some(self.bread.get(id)?.to_proto(*id)))
+            .collect();
+        message.moved_bread = self
+            .bread
+            .iter()
+            .filter(|(id, _)| self.moved_bread_ids.contains(id))
+            .map(|(id, bread)| bread.to_proto(*id))
+            .collect();
+        message.consumed_bread_ids = self
+            .bread_pickups
+            .iter()
+            .map(|(bread_id, _)| *bread_id)
+            .collect();
+        message.bread_pickups = self
+            .bread_pickups
+            .iter()
+            .map(|(bread_id, duck_id)| protos::BreadPickup {
+                bread_id: *bread_id,
+                duck_id: *duck_id,
+                ..Default::default()
+            })
+            .collect();
+    }
 
-        let duck_ids: Vec<u32> = self.ducks.iter().map(|(id, _)| *id).collect();
+    /// Replaces bread events in an UpdateSync with every bread in the pond
+    fn write_full_bread(&self, message: &mut protos::UpdateSync) {
+        message.full_bread = true;
+        message.consumed_bread_ids.clear();
+        message.moved_bread.clear();
+        message.spawned_bread = self.bread_protos();
+    }
 
-        for id in duck_ids {
-            let duck = self.ducks.get_mut(&id).unwrap();
-            for i in 0..highest_scores.len() {
-                if duck.score >= highest_scores[i].0 {
-                    highest_scores.insert(i, (duck.score, id));
-                    highest_scores.pop();
-                    break;
-                }
+    /// Writes the oldest bread not yet sent to legacy clients to an UpdateSync
+    ///
+    /// Several bread can spawn between updates, so they're queued to not be dropped
+    fn write_legacy_bread(&mut self, message: &mut protos::UpdateSync) {
+        // bread eaten while queued is skipped
+        while let Some(id) = self.legacy_bread_queue.pop_front() {
+            if let Some(bread) = self.bread.get(&id) {
+                message.bread_x = Some(bread.x);
+                message.bread_y = Some(bread.y);
+                message.bread_z = Some(bread.z);
+                return;
             }
-            duck.x = 0.0;
-            duck.y = 0.0;
-            duck.z = 4.0;
-            duck.rotation_radians = 0.0;
         }
+    }
 
-        for i in 0..highest_scores.len() {
-            let id = highest_scores[i].1;
-            if id == 0 {
-                log::warn!("ID = 0 ON PODIUM");
-                continue;
-            }
-            let duck = self.ducks.get_mut(&id).unwrap();
-            duck.x = -1.25 + i as f32 * 1.25;
-            duck.y = 0.0;
-            duck.z = -0.5;
-            duck.rotation_radians = 0.0;
+    /// Returns an error unless the lobby is in the given phase
+    pub fn check_phase(&self, phase: Phase) -> Result<(), GameError> {
+        match self.phase == phase {
+            true => Ok(()),
+            false => Err(GameError::WrongPhase(self.phase)),
+        }
+    }
+
+    /// Moves the lobby to the given phase and tells all players
+    fn set_phase(&mut self, phase: Phase) {
+        log::info!(
+            "LOBBY {} IS NOW IN {} PHASE",
+            self.lobby_code,
+            phase.to_string().to_uppercase()
+        );
+        self.phase = phase;
+        self.phase_tick = self.tick;
+        if phase.ducks_frozen() {
+            // authoritative ducks would keep moving on their last input once unfrozen
+            self.ducks
+                .values_mut()
+                .for_each(|duck| duck.input = Default::default());
+        }
+
+        let remaining = self.phase_remaining();
+        self.player_actors.iter().for_each(|(_, player)| {
+            player.do_send(messages::CastPhase { phase, remaining });
+        });
+    }
+
+    /// Returns how long the current phase lasts
+    ///
+    /// Waiting lasts until a round is started
+    fn phase_duration(&self) -> Option<Duration> {
+        match self.phase {
+            Phase::Waiting => None,
+            Phase::Countdown => Some(self.config.countdown()),
+            Phase::Playing => Some(self.settings.game_duration()),
+            Phase::Podium => Some(self.config.podium()),
+        }
+    }
+
+    /// Returns time left in the current phase, zero if it has no end
+    pub fn phase_remaining(&self) -> Duration {
+        let elapsed = self.config.tick_interval() * (self.tick - self.phase_tick) as u32;
+        self.phase_duration()
+            .map_or(Duration::ZERO, |duration| duration.saturating_sub(elapsed))
+    }
+
+    /// Returns whether the current phase has lasted its duration
+    fn phase_over(&self) -> bool {
+        self.phase_duration().is_some_and(|duration| {
+            let phase_ticks = (duration.as_secs_f64() * self.config.tick_rate as f64).ceil();
+            (self.tick - self.phase_tick) as f64 >= phase_ticks
+        })
+    }
+
+    /// Starts the countdown to a round for all ducks in lobby
+    ///
+    /// The round's rng is seeded with the given seed, the configured round seed,
+    /// or a random one, so a round's bread can be reproduced from its logged seed
+    pub fn start_game(&mut self, seed: Option<u64>) {
+        if self.phase != Phase::Waiting {
+            return;
         }
+        self.seed = seed.or(self.config.round_seed).unwrap_or_else(rand::random);
+        self.start_votes.clear();
+        self.set_phase(Phase::Countdown);
+    }
+
+    /// Cancels the countdown, going back to waiting for a start
+    pub fn cancel_countdown(&mut self) {
+        log::info!("CANCELLED COUNTDOWN IN LOBBY {}", self.lobby_code);
+        self.set_phase(Phase::Waiting);
+        self.reset_votes();
+    }
+
+    /// Starts the round once the countdown is over
+    fn begin_round(&mut self) {
+        let seed = self.seed;
+        self.rng = ChaCha8Rng::seed_from_u64(seed);
+        log::info!(
+            "STARTED GAME IN LOBBY {} WITH {} DUCKS WITH DURATION {} ON MAP {} AND SEED {seed}",
+            self.lobby_code,
+            self.ducks.len(),
+            self.settings.game_duration_secs,
+            self.settings.map
+        );
+        let start_time = SystemTime::now();
+        let game_duration = self.settings.game_duration();
+
+        self.player_actors.iter().for_each(|(_, player)| {
+            player.do_send(messages::CastStartGame {
+                start_time,
+                game_duration,
+            })
+        });
+
+        self.set_phase(Phase::Playing);
+        self.start_time = Some(start_time);
+        self.clear_bread();
+        // every round is scored on its own for match history
+        self.ducks.values_mut().for_each(|duck| duck.score = 0);
+
+        if !self.spectator_ids.is_empty() {
+            log::info!(
+                "PROMOTED {} SPECTATORS TO PLAYERS",
+                self.spectator_ids.len()
+            );
+            self.spectator_ids.clear();
+        }
+
+        self.start_recording(start_time, game_duration);
+    }
 
-        log::info!("ENDED GAME");
+    /// Removes bread left over from the previous round, sending players the empty pond
+    fn clear_bread(&mut self) {
+        self.bread.clear();
+        self.spawned_bread_ids.clear();
+        self.moved_bread_ids.clear();
+        self.legacy_bread_queue.clear();
+        self.round_bread_spawned = 0;
+        self.bread_resync_ids.extend(self.player_actors.keys());
     }
 
-    /// Apply updates to all lobbies
-    fn update(&mut self) {
-        let game_over = self.start_time.is_some()
-            && std::time::SystemTime::now()
-                .duration_since(self.start_time.unwrap())
-                .unwrap()
-                >= self.game_duration;
-        if game_over {
-            self.end_game();
-        } else {
-            let delta_time = self.current_time.elapsed().unwrap().as_secs_f32();
-            self.current_time = std::time::SystemTime::now();
-            self.tick_game(delta_time);
+    /// Starts recording the round to a new replay file if replays are enabled
+    ///
+    /// The replay begins with every duck in the lobby and the current world state
+    fn start_recording(&mut self, start_time: SystemTime, game_duration: Duration) {
+        let Some(directory) = &self.config.replay_dir else {
+            return;
+        };
+        match ReplayRecorder::create(directory, &self.lobby_code) {
+            Ok(recorder) => {
+                log::info!("RECORDING ROUND TO REPLAY {}", recorder.id);
+                self.replay = Some(recorder);
+            }
+            Err(e) => {
+                log::error!("Failed to create replay for lobby {}: {e}", self.lobby_code);
+                return;
+            }
         }
 
+        let join_casts: Vec<protos::JoinGameCast> = self
+            .ducks
+            .iter()
+            .map(|(id, duck)| protos::JoinGameCast {
+                id: *id,
+                name: duck.name.clone().unwrap_or_default(),
+                variety: duck.variety.clone().unwrap_or_default(),
+                color: duck.color.clone().unwrap_or_default(),
+                spectator: self.spectator_ids.contains(id),
+                host: self.host_id == Some(*id),
+                team: duck.team,
+                ..Default::default()
+            })
+            .collect();
+        for cast in join_casts {
+            self.record(replay_event::Event::JoinGame(cast));
+        }
+
+        let start_time = start_time
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |start_time| start_time.as_secs());
+        self.record(replay_event::Event::StartGame(protos::StartGameCast {
+            start_time,
+            game_duration: game_duration.as_secs(),
+            ..Default::default()
+        }));
+
         let mut update_message = self.get_update_sync_proto();
+        update_message.tick = self.tick;
+        update_message.full_bread = true;
+        update_message.spawned_bread = self.bread_protos();
+        self.record(replay_event::Event::UpdateSync(update_message));
+    }
 
-        if let Some((x, y, z)) = self.spawn_new_bread() {
-            update_message.bread_x = Some(x);
-            update_message.bread_y = Some(y);
-            update_message.bread_z = Some(z);
+    /// Appends an event to the round's replay if it's being recorded
+    ///
+    /// Recording stops if the replay can't be written
+    pub fn record(&mut self, event: replay_event::Event) {
+        let Some(replay) = &mut self.replay else {
+            return;
+        };
+        if let Err(e) = replay.record(event) {
+            log::error!("Failed to record replay {}: {e}", replay.id);
+            self.replay = None;
         }
-        let update_data = update_message.write_to_bytes().unwrap();
+    }
+
+    /// Clears all votes to start, and notifies players of the new vote status
+    pub fn reset_votes(&mut self) {
+        self.start_votes.clear();
+        self.broadcast_vote_status();
+    }
+
+    /// Sends number of votes to start and votes required to all players
+    ///
+    /// Only ducks with a connected player are counted
+    pub fn broadcast_vote_status(&self) {
+        let votes = self.start_votes.len();
+        let ducks = self.player_actors.len();
+        let required_votes = self.config.vote_threshold.required_votes(ducks);
 
-        // PERF having to clone this is something to look at improving
         self.player_actors.iter().for_each(|(_, player)| {
-            player.do_send(messages::CastUpdateGame {
-                update_data: update_data.clone(),
+            player.do_send(messages::CastVoteStatus {
+                votes,
+                ducks,
+                required_votes,
             });
         });
+    }
 
-        if game_over {
-            self.player_actors.iter().for_each(|(_, player)| {
-                player.do_send(messages::CastEndGame {});
-            });
-            self.start_time = None;
+    /// Ends the round, moving ducks to the podium and notifying players of the reason
+    ///
+    /// In team mode, ducks are ranked by their team's score first. Failing to send
+    /// the final update or save the round is logged, the round still ends
+    pub fn end_game(&mut self, reason: messages::EndReason) {
+        let mut duck_ids: Vec<u32> = self.playing_duck_ids();
+        duck_ids.sort_by_key(|id| std::cmp::Reverse(self.ducks[id].score));
+
+        let team_scores = self.team_scores();
+        let mut ranked_teams: Vec<usize> = (0..team_scores.len()).collect();
+        ranked_teams.sort_by_key(|team| std::cmp::Reverse(team_scores[*team]));
+        let team_rank = |duck: &Duck| {
+            duck.team
+                .and_then(|team| ranked_teams.iter().position(|t| *t == team as usize))
+        };
+        // stable sort keeps ducks ranked by score within their team
+        duck_ids.sort_by_key(|id| team_rank(&self.ducks[id]));
+        if let Some(&team) = ranked_teams.first() {
+            log::info!("TEAM {team} WON WITH {} POINTS", team_scores[team]);
+        }
+
+        let mut teammates_placed = vec![0; ranked_teams.len()];
+        for (i, id) in duck_ids.iter().enumerate() {
+            // top 3 ducks stand on the podium, or the top 3 teams lined up on it
+            let (place, line) = match team_rank(&self.ducks[id]) {
+                Some(rank) => {
+                    teammates_placed[rank] += 1;
+                    (rank, teammates_placed[rank] - 1)
+                }
+                None => (i, 0),
+            };
+            let duck = self.ducks.get_mut(id).unwrap();
+            if place < 3 {
+                duck.x = -1.25 + place as f32 * 1.25;
+                duck.z = -0.5 + line as f32 * 0.75;
+            } else {
+                duck.x = 0.0;
+                duck.z = 4.0;
+            }
+            duck.y = 0.0;
+            duck.rotation_radians = 0.0;
+            // ducks are teleported, so next client update can't be speed checked
+            duck.last_update_time = None;
+        }
+        // power-ups only last for the round
+        self.power_ups.clear();
+        self.ducks.values_mut().for_each(|duck| {
+            duck.effects.clear();
+            duck.slowed = Duration::ZERO;
+        });
+
+        log::info!("ENDED GAME ({reason})");
+
+        self.player_actors.iter().for_each(|(_, player)| {
+            player.do_send(messages::CastEndGame { reason });
+        });
+        self.set_phase(Phase::Podium);
+        if let Err(e) = self.broadcast_update() {
+            log::error!("Failed to update lobby {}: {e}", self.lobby_code);
+        }
+        if let Err(e) = self.save_round(&duck_ids) {
+            log::error!("Failed to save round in lobby {}: {e}", self.lobby_code);
+        }
+        self.start_time = None;
+
+        self.record(replay_event::Event::EndGame(messages::end_game_cast(
+            reason,
+        )));
+        if let Some(mut replay) = self.replay.take() {
+            match replay.finish() {
+                Ok(()) => log::info!("SAVED REPLAY {}", replay.id),
+                Err(e) => log::error!("Failed to save replay {}: {e}", replay.id),
+            }
+        }
+    }
+
+    /// Sends the finished round's results, ranked by given duck ids, to match history
+    fn save_round(&self, ranked_ids: &[u32]) -> Result<(), GameError> {
+        let unix_secs = |time: SystemTime| {
+            time.duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .map_err(GameError::Clock)
+        };
+        let Some(start_time) = self.start_time else {
+            return Ok(());
+        };
+
+        let participants = ranked_ids
+            .iter()
+            .map(|id| {
+                let duck = &self.ducks[id];
+                actors::RoundParticipant {
+                    duck_id: *id,
+                    name: duck.name.clone().unwrap_or_default(),
+                    variety: duck.variety.clone().unwrap_or_default(),
+                    color: duck.color.clone().unwrap_or_default(),
+                    score: duck.score,
+                    team: duck.team,
+                }
+            })
+            .collect();
+        self.storage_address.do_send(messages::RecordRound {
+            round: actors::RoundRecord {
+                lobby_code: self.lobby_code.clone(),
+                started_at: unix_secs(start_time)?,
+                ended_at: unix_secs(SystemTime::now())?,
+                duration_secs: self.settings.game_duration_secs,
+                seed: self.seed,
+                replay_id: self.replay.as_ref().map(|replay| replay.id.clone()),
+                participants,
+            },
+        });
+        Ok(())
+    }
+
+    /// Returns why the running round should end, if it should
+    fn round_over(&self) -> Option<messages::EndReason> {
+        if self.phase_over() {
+            return Some(messages::EndReason::TimeUp);
+        }
+        let all_bread_eaten = self.config.end_when_all_bread_eaten
+            && self.round_bread_spawned >= self.settings.bread_limit
+            && self.bread.is_empty();
+        all_bread_eaten.then_some(messages::EndReason::AllBreadEaten)
+    }
+
+    /// Advances the simulation by fixed ticks for the time elapsed since the last call
+    ///
+    /// Leftover time is carried to the next call, so the tick rate holds regardless
+    /// of timer jitter
+    fn simulate(&mut self) {
+        let now = Instant::now();
+        let tick_interval = self.config.tick_interval();
+        self.tick_accumulator = (self.tick_accumulator + (now - self.last_tick_time))
+            .min(tick_interval * MAX_CATCH_UP_TICKS);
+        self.last_tick_time = now;
+
+        while self.tick_accumulator >= tick_interval {
+            self.tick_accumulator -= tick_interval;
+            self.tick += 1;
+            match self.phase {
+                Phase::Waiting => self.tick_game(tick_interval.as_secs_f32()),
+                Phase::Countdown if self.phase_over() => self.begin_round(),
+                Phase::Playing => match self.round_over() {
+                    Some(reason) => self.end_game(reason),
+                    None => {
+                        self.tick_game(tick_interval.as_secs_f32());
+                        self.spawn_new_bread();
+                        self.spawn_new_power_up();
+                    }
+                },
+                Phase::Podium if self.phase_over() => {
+                    self.set_phase(Phase::Waiting);
+                    self.reset_votes();
+                }
+                Phase::Countdown | Phase::Podium => {}
+            }
+        }
+    }
+
+    /// Sends world state to all players, as a full snapshot or a delta from their acknowledged one
+    fn broadcast_update(&mut self) -> Result<(), GameError> {
+        let player_ids: Vec<u32> = self.player_actors.keys().copied().collect();
+        for (id, update_data) in self.build_updates(&player_ids)? {
+            if let Some(player) = self.player_actors.get(&id) {
+                player.do_send(messages::CastUpdateGame { update_data });
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the serialized `UpdateSync` for each of the given players, and clears
+    /// events sent with it
+    ///
+    /// Players marked for a bread resync are sent every bread, whether they get a
+    /// full snapshot or a delta
+    fn build_updates(&mut self, player_ids: &[u32]) -> Result<Vec<(u32, Vec<u8>)>, GameError> {
+        let mut update_message = self.get_update_sync_proto();
+        update_message.tick = self.tick;
+        self.write_bread_events(&mut update_message);
+        self.write_legacy_bread(&mut update_message);
+        update_message.duck_collisions = self
+            .duck_collisions
+            .iter()
+            .map(DuckCollision::to_proto)
+            .collect();
+        let snapshot = self.snapshots.push(
+            self.ducks
+                .iter()
+                .filter(|(id, _)| !self.spectator_ids.contains(id))
+                .map(|(id, duck)| (*id, QuantizedDuck::new(duck)))
+                .collect(),
+        );
+        update_message.snapshot = snapshot;
+        if self.replay.is_some() {
+            self.record(replay_event::Event::UpdateSync(update_message.clone()));
         }
+        let update_data = update_message
+            .write_to_bytes()
+            .map_err(GameError::Serialize)?;
+
+        // deltas are shared by players acknowledging the same baseline
+        let mut delta_data: HashMap<(u32, bool), Vec<u8>> = HashMap::new();
+        let mut full_bread_data: Option<Vec<u8>> = None;
+        let mut updates = Vec::with_capacity(player_ids.len());
+        for id in player_ids {
+            let baseline = self.acked_snapshots.get(id).copied().unwrap_or(0);
+            let resync = self.bread_resync_ids.remove(id);
+            if !self.snapshots.contains(baseline) {
+                // players that just joined, or fell behind their acknowledged snapshot,
+                // are sent every bread to replace their own
+                let resync = resync || self.acked_snapshots.remove(id).is_some();
+                let data = match (resync, &mut full_bread_data) {
+                    (false, _) => &update_data,
+                    (true, Some(data)) => data,
+                    (true, full_bread_data) => {
+                        let mut full_bread_message = update_message.clone();
+                        self.write_full_bread(&mut full_bread_message);
+                        full_bread_data.insert(
+                            full_bread_message
+                                .write_to_bytes()
+                                .map_err(GameError::Serialize)?,
+                        )
+                    }
+                };
+                // PERF having to clone this is something to look at improving
+                updates.push((*id, data.clone()));
+                continue;
+            }
+
+            let data = match delta_data.entry((baseline, resync)) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let mut delta_message = protos::UpdateSync {
+                        snapshot,
+                        tick: self.tick,
+                        team_scores: update_message.team_scores.clone(),
+                        power_ups: update_message.power_ups.clone(),
+                        duck_effects: update_message.duck_effects.clone(),
+                        slowed_ducks: update_message.slowed_ducks.clone(),
+                        duck_collisions: update_message.duck_collisions.clone(),
+                        ..Default::default()
+                    };
+                    match resync {
+                        true => self.write_full_bread(&mut delta_message),
+                        false => self.write_bread_events(&mut delta_message),
+                    }
+                    self.snapshots.write_delta(baseline, &mut delta_message);
+                    entry.insert(
+                        delta_message
+                            .write_to_bytes()
+                            .map_err(GameError::Serialize)?,
+                    )
+                }
+            };
+            updates.push((*id, data.clone()));
+        }
+
+        self.spawned_bread_ids.clear();
+        self.moved_bread_ids.clear();
+        self.bread_pickups.clear();
+        self.duck_collisions.clear();
+        Ok(updates)
+    }
+
+    /// Closes lobby once it has stayed empty for the idle timeout
+    ///
+    /// Marks the lobby as empty now if it wasn't already
+    pub fn schedule_close(&mut self, context: &mut Context<Self>) {
+        self.empty_since.get_or_insert_with(Instant::now);
+
+        let idle_timeout = self.config.lobby_idle_timeout();
+        context.run_later(idle_timeout, move |server_actor, context| {
+            let idle = server_actor
+                .empty_since
+                .is_some_and(|empty_since| empty_since.elapsed() >= idle_timeout);
+            if idle && server_actor.ducks.is_empty() {
+                server_actor
+                    .lobby_manager_address
+                    .do_send(messages::CloseLobby {
+                        code: server_actor.lobby_code.clone(),
+                        server_address: context.address(),
+                    });
+                context.stop();
+            }
+        });
     }
 }
 
@@ -216,8 +1227,105 @@ impl Actor for GameServer {
     type Context = Context<Self>;
 
     fn started(&mut self, context: &mut Self::Context) {
-        context.run_interval(UPDATE_SYNC_INTERVAL, |server_actor, _context| {
-            server_actor.update();
+        context.run_interval(self.config.tick_interval(), |server_actor, _context| {
+            server_actor.simulate();
+        });
+        context.run_interval(
+            self.config.update_sync_interval(),
+            |server_actor, _context| {
+                if let Err(e) = server_actor.broadcast_update() {
+                    log::error!("Failed to update lobby {}: {e}", server_actor.lobby_code);
+                }
+            },
+        );
+        context.run_interval(PLAYER_REAP_INTERVAL, |server_actor, context| {
+            server_actor.reap_stopped_players(context);
+        });
+        self.schedule_close(context);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs the test with a lobby that has no players connected
+    fn with_server(test: impl FnOnce(&mut GameServer)) {
+        System::new().block_on(async {
+            let config = Arc::new(Config::default());
+            let storage_address = actors::Storage {
+                connection: rusqlite::Connection::open_in_memory().unwrap(),
+            }
+            .start();
+            let lobby_manager_address =
+                actors::LobbyManager::new(storage_address.clone(), config.clone()).start();
+            let mut server = GameServer::new(
+                "test".to_owned(),
+                lobby_manager_address,
+                storage_address,
+                config,
+            );
+            test(&mut server);
+        });
+    }
+
+    fn update_for(server: &mut GameServer, id: u32) -> protos::UpdateSync {
+        let updates = server.build_updates(&[id]).unwrap();
+        protos::UpdateSync::parse_from_bytes(&updates[0].1).unwrap()
+    }
+
+    #[test]
+    fn acknowledging_player_is_sent_emptied_pond() {
+        with_server(|server| {
+            server.ducks.insert(1, Duck::new());
+            server
+                .bread
+                .insert(7, Bread::new(BreadKind::Crust, 1.0, 1.0));
+            server.bread_resync_ids.insert(1);
+            let update = update_for(server, 1);
+            assert!(update.full_bread);
+            assert_eq!(update.spawned_bread.len(), 1);
+
+            server.acked_snapshots.insert(1, update.snapshot);
+            let update = update_for(server, 1);
+            assert!(!update.full_bread);
+            assert!(update.spawned_bread.is_empty());
+
+            server.clear_bread();
+            server.bread_resync_ids.insert(1);
+            let update = update_for(server, 1);
+            assert!(update.full_bread);
+            assert!(update.spawned_bread.is_empty());
+            assert!(server.bread_resync_ids.is_empty());
+
+            let update = update_for(server, 1);
+            assert!(!update.full_bread);
+        });
+    }
+
+    #[test]
+    fn bread_pulled_by_magnet_is_sent_moved() {
+        with_server(|server| {
+            let mut duck = Duck::new();
+            duck.add_effect(PowerUpKind::Magnet);
+            server.ducks.insert(1, duck);
+            server
+                .bread
+                .insert(7, Bread::new(BreadKind::Crust, 2.0, 0.0));
+            server
+                .bread
+                .insert(8, Bread::new(BreadKind::Crust, 8.0, 0.0));
+            server.bread_resync_ids.insert(1);
+            update_for(server, 1);
+
+            server.tick_game(0.1);
+            let update = update_for(server, 1);
+            assert_eq!(update.moved_bread.len(), 1);
+            assert_eq!(update.moved_bread[0].id, 7);
+            assert!(update.moved_bread[0].x < 2.0);
+            assert_eq!(server.bread[&7].x, update.moved_bread[0].x);
+
+            assert!(update_for(server, 1).moved_bread.is_empty());
         });
     }
 }
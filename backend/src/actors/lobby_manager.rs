@@ -0,0 +1,83 @@
+use crate::{actors, config::Config, error::GameError};
+use actix::prelude::*;
+use std::{collections::HashMap, sync::Arc};
+
+/// Lobby code used when a client connects without specifying one
+pub const DEFAULT_LOBBY_CODE: &str = "PUBLIC";
+const LOBBY_CODE_MAX_LENGTH: usize = 8;
+
+/// A lobby manager actor
+///
+/// Contains registry of lobby codes to `GameServer` actor addresses
+///
+/// Starts a new `GameServer` when a player joins a lobby that isn't running,
+/// and forgets lobbies once their `GameServer` closes itself from being empty
+#[derive(Debug)]
+pub struct LobbyManager {
+    pub lobbies: HashMap<String, Addr<actors::GameServer>>,
+    pub storage_address: Addr<actors::Storage>,
+    pub config: Arc<Config>,
+}
+
+impl LobbyManager {
+    pub fn new(storage_address: Addr<actors::Storage>, config: Arc<Config>) -> LobbyManager {
+        LobbyManager {
+            lobbies: HashMap::new(),
+            storage_address,
+            config,
+        }
+    }
+
+    /// Returns address of the lobby with given code
+    ///
+    /// Starts a new game server if lobby doesn't exist or has already stopped,
+    /// unless `max_lobbies` are already running
+    pub fn get_or_create_lobby(
+        &mut self,
+        code: &str,
+        context: &mut Context<Self>,
+    ) -> Result<Addr<actors::GameServer>, GameError> {
+        if let Some(server) = self.lobbies.get(code) {
+            if server.connected() {
+                return Ok(server.clone());
+            }
+        }
+        self.lobbies.retain(|_, server| server.connected());
+        if self.lobbies.len() >= self.config.max_lobbies {
+            log::warn!(
+                "NOT CREATING LOBBY {code}, {} LOBBIES RUNNING",
+                self.lobbies.len()
+            );
+            return Err(GameError::LobbyUnavailable);
+        }
+
+        log::info!("CREATED LOBBY {code}");
+        let server = actors::GameServer::new(
+            code.to_owned(),
+            context.address(),
+            self.storage_address.clone(),
+            self.config.clone(),
+        )
+        .start();
+        self.lobbies.insert(code.to_owned(), server.clone());
+        Ok(server)
+    }
+}
+
+impl Actor for LobbyManager {
+    type Context = Context<Self>;
+}
+
+/// Validates a client provided lobby code
+///
+/// Returns the uppercased code if it's 1-8 ascii alphanumeric characters, otherwise None
+pub fn normalize_lobby_code(code: &str) -> Option<String> {
+    let code = code.trim();
+    if code.is_empty()
+        || code.len() > LOBBY_CODE_MAX_LENGTH
+        || !code.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return None;
+    }
+    Some(code.to_ascii_uppercase())
+}
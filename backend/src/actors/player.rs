@@ -1,17 +1,36 @@
-use std::time::{Duration, Instant};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use actix::prelude::*;
 use actix_web_actors::ws;
 
-use crate::{actors, duck::Duck, messages, protos::protos::protos};
+use crate::{
+    actors,
+    config::Config,
+    duck::{Duck, DuckInput, MovementViolation},
+    error::GameError,
+    messages,
+    protocol::{self, Protocol, PROTOCOL_VERSION},
+    protos::protos::protos::{self, client_message, server_message},
+    settings::GameSettings,
+};
 use protobuf::Message;
 
-const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
-const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_MOVEMENT_VIOLATIONS: u32 = 30;
+const MOVEMENT_VIOLATION_WINDOW: Duration = Duration::from_secs(60);
+const MAX_MALFORMED_FRAMES: u32 = 20;
 
 /// A player actor, spawned for each client connection
 ///
-/// Contains a player id, a heartbeat for connection, and game server address
+/// Contains a player id, a heartbeat for connection, the requested lobby code,
+/// and game server address once the lobby has been joined
+///
+/// Client picks its protocol with its first frame: a binary `Hello` for the
+/// structured protocol, or anything else for the legacy text protocol
+///
+/// Legacy binary frames are parsed as `DuckInput` with authoritative movement, otherwise as `Duck`
 ///
 /// `Player` communicates with client via websocket stream
 /// and communicates with `GameServer` actor through messages
@@ -19,21 +38,31 @@ const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
 pub struct Player {
     pub id: u32,
     pub last_heartbeat_time: Instant,
-    pub server_address: Addr<actors::game_server::GameServer>,
+    pub lobby_code: String,
+    pub lobby_manager_address: Addr<actors::LobbyManager>,
+    pub server_address: Option<Addr<actors::GameServer>>,
+    pub config: Arc<Config>,
+    pub movement_violations: u32,
+    pub movement_violation_window_start: Instant,
+    pub protocol: Option<Protocol>,
+    pub malformed_frames: u32,
+    /// Set once the client is kicked, so its duck is removed instead of kept for resuming
+    pub kicked: bool,
+    /// Set while a join or resume request is waiting for the game server
+    pub join_pending: bool,
 }
 
 impl Player {
     fn heartbeat(&self, ctx: &mut ws::WebsocketContext<Self>) {
-        ctx.run_interval(HEARTBEAT_INTERVAL, |actor, context| {
+        ctx.run_interval(self.config.heartbeat_interval(), |actor, context| {
             // check client heartbeats
-            if Instant::now().duration_since(actor.last_heartbeat_time) > CLIENT_TIMEOUT {
+            if Instant::now().duration_since(actor.last_heartbeat_time)
+                > actor.config.client_timeout()
+            {
                 log::info!(
                     "Websocket Client ({}) heartbeat failed, disconnecting!",
                     actor.id
                 );
-                actor
-                    .server_address
-                    .do_send(messages::LeaveGame { id: actor.id });
                 context.stop();
                 return;
             }
@@ -41,6 +70,347 @@ impl Player {
             context.ping(b"");
         });
     }
+
+    /// Counts a rejected move from this client, kicking it past the violation limit
+    ///
+    /// Violations are counted within a window so occasional lag spikes don't add up
+    pub fn record_movement_violation(
+        &mut self,
+        violation: MovementViolation,
+        context: &mut ws::WebsocketContext<Self>,
+    ) {
+        if self.movement_violation_window_start.elapsed() > MOVEMENT_VIOLATION_WINDOW {
+            self.movement_violations = 0;
+            self.movement_violation_window_start = Instant::now();
+        }
+        self.movement_violations += 1;
+
+        log::warn!(
+            "Websocket Client ({}) movement violation {}/{}: {violation}",
+            self.id,
+            self.movement_violations,
+            MAX_MOVEMENT_VIOLATIONS
+        );
+
+        if self.movement_violations >= MAX_MOVEMENT_VIOLATIONS {
+            self.kick(&format!("too many invalid moves: {violation}"), context);
+        }
+    }
+
+    /// Marks a join or resume as started, failing if the client already has a duck
+    ///
+    /// A connection controls a single duck, so repeated requests are rejected
+    fn start_join(&mut self) -> Result<(), GameError> {
+        if self.server_address.is_some() || self.join_pending {
+            return Err(GameError::AlreadyInGame);
+        }
+        self.join_pending = true;
+        Ok(())
+    }
+
+    /// Tells client why it's being disconnected, then closes the websocket
+    ///
+    /// The duck of a kicked client can't be resumed
+    pub fn kick(&mut self, reason: &str, context: &mut ws::WebsocketContext<Self>) {
+        log::info!("Websocket Client ({}) kicked: {reason}", self.id);
+        self.kicked = true;
+        self.send(
+            server_message::Message::Kick(protos::KickCast {
+                reason: reason.to_owned(),
+                ..Default::default()
+            }),
+            context,
+        );
+        context.close(Some(ws::CloseReason {
+            code: ws::CloseCode::Policy,
+            description: Some(reason.to_owned()),
+        }));
+        context.stop();
+    }
+}
+
+impl Player {
+    /// Sends a message to the client in the framing of its protocol
+    pub fn send(&self, message: server_message::Message, context: &mut ws::WebsocketContext<Self>) {
+        match self.protocol {
+            Some(Protocol::Structured) => {
+                let mut envelope = protos::ServerMessage::new();
+                envelope.message = Some(message);
+                match envelope.write_to_bytes() {
+                    Ok(bytes) => context.binary(bytes),
+                    Err(e) => log::error!("{}", GameError::Serialize(e)),
+                }
+            }
+            _ => {
+                if let Some(text) = protocol::legacy_text(&message) {
+                    context.text(text);
+                }
+            }
+        }
+    }
+
+    /// Accepts a structured protocol client if it speaks this server's version
+    fn handshake(&mut self, protocol_version: u32, context: &mut ws::WebsocketContext<Self>) {
+        if protocol_version != PROTOCOL_VERSION {
+            self.kick(
+                &format!(
+                    "unsupported protocol version {protocol_version}, server speaks {PROTOCOL_VERSION}"
+                ),
+                context,
+            );
+            return;
+        }
+
+        self.protocol = Some(Protocol::Structured);
+        self.send(
+            server_message::Message::Hello(protos::Hello {
+                protocol_version: PROTOCOL_VERSION,
+                ..Default::default()
+            }),
+            context,
+        );
+    }
+
+    /// Reports an error back to the client
+    ///
+    /// Malformed frames are counted, and the client is kicked past the limit
+    pub fn report_error(&mut self, error: GameError, context: &mut ws::WebsocketContext<Self>) {
+        log::warn!("Websocket Client ({}) error: {error}", self.id);
+
+        self.send(
+            server_message::Message::Error(protos::ErrorCast {
+                message: error.to_string(),
+                ..Default::default()
+            }),
+            context,
+        );
+
+        if error.is_malformed_frame() {
+            self.malformed_frames += 1;
+            if self.malformed_frames >= MAX_MALFORMED_FRAMES {
+                self.kick("too many malformed frames", context);
+            }
+        }
+    }
+
+    /// Sends a message to the joined game server, reporting any error it returns
+    fn send_to_server<M>(
+        &self,
+        message: M,
+        context: &mut ws::WebsocketContext<Self>,
+    ) -> Result<(), GameError>
+    where
+        M: actix::Message<Result = Result<(), GameError>> + Send + 'static,
+        actors::GameServer: Handler<M>,
+    {
+        let server_address = self.server_address.as_ref().ok_or(GameError::NotInGame)?;
+        server_address
+            .send(message)
+            .into_actor(self)
+            .map(|result, actor, context| match result {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => actor.report_error(e, context),
+                Err(_) => actor.report_error(GameError::LobbyUnavailable, context),
+            })
+            .spawn(context);
+        Ok(())
+    }
+
+    /// Parses a frame from the client into a client message
+    ///
+    /// Returns None for frames that don't carry a message, like the handshake
+    fn parse_frame(
+        &mut self,
+        frame: ws::Message,
+        context: &mut ws::WebsocketContext<Self>,
+    ) -> Result<Option<client_message::Message>, GameError> {
+        match frame {
+            ws::Message::Text(text) => {
+                match self.protocol.get_or_insert(Protocol::Legacy) {
+                    Protocol::Legacy if self.config.legacy_protocol => {}
+                    Protocol::Legacy => {
+                        self.kick("legacy text protocol is disabled", context);
+                        return Ok(None);
+                    }
+                    Protocol::Structured => return Err(GameError::UnexpectedFrame),
+                }
+
+                protocol::parse_legacy_text(&text)
+                    .map(Some)
+                    .ok_or(GameError::MalformedText)
+            }
+            ws::Message::Binary(bytes) => match self.protocol {
+                None => {
+                    // first binary frame must be the structured protocol handshake
+                    let message = protos::ClientMessage::parse_from_bytes(&bytes)
+                        .map_err(GameError::MalformedFrame)?;
+                    match message.message {
+                        Some(client_message::Message::Hello(hello)) => {
+                            self.handshake(hello.protocol_version, context);
+                            Ok(None)
+                        }
+                        _ => Err(GameError::UnexpectedFrame),
+                    }
+                }
+                Some(Protocol::Structured) => {
+                    let message = protos::ClientMessage::parse_from_bytes(&bytes)
+                        .map_err(GameError::MalformedFrame)?;
+                    Ok(message.message)
+                }
+                Some(Protocol::Legacy) if self.config.authoritative_movement => {
+                    let input = protos::DuckInput::parse_from_bytes(&bytes)
+                        .map_err(GameError::MalformedFrame)?;
+                    Ok(Some(client_message::Message::Input(input)))
+                }
+                Some(Protocol::Legacy) => {
+                    let duck = protos::Duck::parse_from_bytes(&bytes)
+                        .map_err(GameError::MalformedFrame)?;
+                    Ok(Some(client_message::Message::Update(duck)))
+                }
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Handles a message from the client, regardless of the protocol it was sent in
+    fn handle_client_message(
+        &mut self,
+        message: client_message::Message,
+        context: &mut ws::WebsocketContext<Self>,
+    ) -> Result<(), GameError> {
+        match message {
+            client_message::Message::Hello(_) => Err(GameError::UnexpectedFrame),
+            client_message::Message::JoinGame(join_game) => {
+                self.start_join()?;
+                log::info!(
+                    "joined: {} {} {}",
+                    join_game.name,
+                    join_game.variety,
+                    join_game.color
+                );
+
+                self.lobby_manager_address
+                    .send(messages::JoinGame {
+                        player_address: context.address(),
+                        lobby_code: self.lobby_code.clone(),
+                        name: join_game.name,
+                        variety: join_game.variety,
+                        color: join_game.color,
+                        team: join_game.team,
+                    })
+                    .into_actor(self)
+                    .map(|result, actor, context| {
+                        actor.join_pending = false;
+                        if let Err(e) = result.unwrap_or(Err(GameError::LobbyUnavailable)) {
+                            actor.report_error(e, context);
+                        }
+                    })
+                    .spawn(context);
+                Ok(())
+            }
+            client_message::Message::ResumeGame(resume_game) => {
+                self.start_join()?;
+                self.lobby_manager_address
+                    .send(messages::ResumeGame {
+                        player_address: context.address(),
+                        lobby_code: self.lobby_code.clone(),
+                        resume_token: resume_game.resume_token,
+                    })
+                    .into_actor(self)
+                    .map(|result, actor, context| {
+                        actor.join_pending = false;
+                        if let Err(e) = result.unwrap_or(Err(GameError::LobbyUnavailable)) {
+                            log::info!("Websocket Client ({}) resume failed: {e}", actor.id);
+                            actor.send(
+                                server_message::Message::ResumeGameFailed(
+                                    protos::ResumeGameFailed::new(),
+                                ),
+                                context,
+                            );
+                        }
+                    })
+                    .spawn(context);
+                Ok(())
+            }
+            client_message::Message::VoteStartGame(vote_start_game) => self.send_to_server(
+                messages::VoteStartGame {
+                    id: self.id,
+                    vote: vote_start_game.vote,
+                },
+                context,
+            ),
+            client_message::Message::ConfigureGame(settings) => self.send_to_server(
+                messages::ConfigureGame {
+                    id: self.id,
+                    settings: GameSettings::from_proto(settings, &self.config),
+                },
+                context,
+            ),
+            client_message::Message::StartGame(_) => self.send_to_server(
+                messages::StartGame {
+                    requester: Some(self.id),
+                    seed: None,
+                },
+                context,
+            ),
+            client_message::Message::Kick(kick) => self.send_to_server(
+                messages::KickDuck {
+                    requester: Some(self.id),
+                    id: kick.id,
+                    reason: "kicked by host".to_owned(),
+                },
+                context,
+            ),
+            client_message::Message::Update(duck) => {
+                // updates sent before the join is acknowledged are dropped
+                if self.server_address.is_none() {
+                    return Ok(());
+                }
+                self.send_to_server(
+                    messages::Update {
+                        id: self.id,
+                        duck: Duck {
+                            x: duck.x,
+                            y: duck.y,
+                            z: duck.z,
+                            rotation_radians: duck.rotation,
+                            ..Duck::new()
+                        },
+                    },
+                    context,
+                )
+            }
+            client_message::Message::Input(input) => {
+                if self.server_address.is_none() {
+                    return Ok(());
+                }
+                self.send_to_server(
+                    messages::Input {
+                        id: self.id,
+                        sequence: input.sequence,
+                        input: DuckInput {
+                            move_x: input.move_x,
+                            move_z: input.move_z,
+                            jump: input.jump,
+                        },
+                    },
+                    context,
+                )
+            }
+            client_message::Message::AckSnapshot(ack) => {
+                if self.server_address.is_none() {
+                    return Ok(());
+                }
+                self.send_to_server(
+                    messages::AckSnapshot {
+                        id: self.id,
+                        snapshot: ack.snapshot,
+                    },
+                    context,
+                )
+            }
+        }
+    }
 }
 
 impl Actor for Player {
@@ -50,10 +420,15 @@ impl Actor for Player {
         self.heartbeat(context);
     }
 
-    fn stopping(&mut self, _: &mut Self::Context) -> Running {
+    fn stopping(&mut self, context: &mut Self::Context) -> Running {
         // notify game server
-        self.server_address
-            .do_send(messages::LeaveGame { id: self.id });
+        if let Some(server_address) = &self.server_address {
+            server_address.do_send(messages::LeaveGame {
+                id: self.id,
+                player_address: context.address(),
+                kicked: self.kicked,
+            });
+        }
         Running::Stop
     }
 }
@@ -82,46 +457,17 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for Player {
             ws::Message::Pong(_) => {
                 self.last_heartbeat_time = Instant::now();
             }
-            ws::Message::Text(text) => {
-                let message = text.trim();
-
-                let v: Vec<&str> = message.splitn(100, '\n').collect();
-
-                match v[0] {
-                    "join_game" => {
-                        let name = v[1].to_owned();
-                        let variety = v[2].to_owned();
-                        let color = v[3].to_owned();
-
-                        self.server_address.do_send(messages::JoinGame {
-                            player_address: context.address(),
-                            name,
-                            variety,
-                            color,
-                        });
-
-                        log::info!("joined: {v:?}");
-                    }
-                    "vote_start_game" => {
-                        // TODO implement vote start system instead
-                        self.server_address.do_send(messages::StartGame {});
-                    }
-                    _ => {}
+            frame @ (ws::Message::Text(_) | ws::Message::Binary(_)) => {
+                let result = self
+                    .parse_frame(frame, context)
+                    .and_then(|message| match message {
+                        Some(message) => self.handle_client_message(message, context),
+                        None => Ok(()),
+                    });
+                if let Err(e) = result {
+                    self.report_error(e, context);
                 }
             }
-            ws::Message::Binary(bytes) => {
-                let in_message = protos::Duck::parse_from_bytes(&bytes).unwrap();
-                self.server_address.do_send(messages::Update {
-                    id: self.id,
-                    duck: Duck {
-                        x: in_message.x,
-                        y: in_message.y,
-                        z: in_message.z,
-                        rotation_radians: in_message.rotation,
-                        ..Duck::new()
-                    },
-                });
-            }
             ws::Message::Close(reason) => {
                 context.close(reason);
                 context.stop();
@@ -0,0 +1,115 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use actix::prelude::*;
+use actix_web_actors::ws;
+use protobuf::Message;
+
+use crate::{
+    protocol::PROTOCOL_VERSION,
+    protos::protos::protos::{self, replay_event, server_message},
+};
+
+/// A replay player actor, spawned for each replay client connection
+///
+/// Streams a recorded round to the client as structured protocol `ServerMessage`
+/// envelopes, starting with a `Hello`, at the recorded pace divided by `speed`
+///
+/// Closes the connection once every event has been sent
+#[derive(Debug)]
+pub struct ReplayPlayer {
+    pub events: Vec<protos::ReplayEvent>,
+    pub speed: f32,
+    pub next_event: usize,
+    pub playback_start: Instant,
+}
+
+impl ReplayPlayer {
+    /// Schedules the next event at its recorded time from the start of playback
+    fn play_next(&mut self, context: &mut ws::WebsocketContext<Self>) {
+        let Some(event) = self.events.get(self.next_event) else {
+            context.close(Some(ws::CloseCode::Normal.into()));
+            context.stop();
+            return;
+        };
+
+        let due = self.playback_start + Duration::from_millis(event.time).div_f32(self.speed);
+        context.run_later(
+            due.saturating_duration_since(Instant::now()),
+            |actor, context| {
+                if let Some(message) = actor.events[actor.next_event].event.clone() {
+                    actor.send(message, context);
+                }
+                actor.next_event += 1;
+                actor.play_next(context);
+            },
+        );
+    }
+
+    /// Sends a recorded event as the message live clients received
+    fn send(&self, event: replay_event::Event, context: &mut ws::WebsocketContext<Self>) {
+        let message = match event {
+            replay_event::Event::UpdateSync(update) => server_message::Message::UpdateSync(update),
+            replay_event::Event::JoinGame(cast) => server_message::Message::JoinGameCast(cast),
+            replay_event::Event::LeaveGame(cast) => server_message::Message::LeaveGameCast(cast),
+            replay_event::Event::StartGame(cast) => {
+                // the round appears to start now, so client timers run from playback
+                let start_time = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(cast.start_time, |now| now.as_secs());
+                server_message::Message::StartGame(protos::StartGameCast { start_time, ..cast })
+            }
+            replay_event::Event::EndGame(cast) => server_message::Message::EndGame(cast),
+        };
+        self.send_message(message, context);
+    }
+
+    fn send_message(
+        &self,
+        message: server_message::Message,
+        context: &mut ws::WebsocketContext<Self>,
+    ) {
+        let envelope = protos::ServerMessage {
+            message: Some(message),
+            ..Default::default()
+        };
+        match envelope.write_to_bytes() {
+            Ok(data) => context.binary(data),
+            Err(e) => log::error!("Failed to serialize replay message: {e}"),
+        }
+    }
+}
+
+impl Actor for ReplayPlayer {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, context: &mut Self::Context) {
+        self.send_message(
+            server_message::Message::Hello(protos::Hello {
+                protocol_version: PROTOCOL_VERSION,
+                ..Default::default()
+            }),
+            context,
+        );
+        self.playback_start = Instant::now();
+        self.play_next(context);
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for ReplayPlayer {
+    /// Answers pings and closes, replays don't take input from the client
+    fn handle(
+        &mut self,
+        message: Result<ws::Message, ws::ProtocolError>,
+        context: &mut Self::Context,
+    ) {
+        match message {
+            Ok(ws::Message::Ping(message)) => context.pong(&message),
+            Ok(ws::Message::Close(reason)) => {
+                context.close(reason);
+                context.stop();
+            }
+            Err(_) => context.stop(),
+            _ => {}
+        }
+    }
+}
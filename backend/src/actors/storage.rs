@@ -0,0 +1,292 @@
+use actix::prelude::*;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::path::Path;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS rounds (
+        id INTEGER PRIMARY KEY,
+        lobby_code TEXT NOT NULL,
+        started_at INTEGER NOT NULL,
+        ended_at INTEGER NOT NULL,
+        duration_secs INTEGER NOT NULL,
+        seed TEXT NOT NULL,
+        replay_id TEXT
+    );
+    CREATE INDEX IF NOT EXISTS rounds_ended_at ON rounds (ended_at);
+    CREATE TABLE IF NOT EXISTS round_participants (
+        round_id INTEGER NOT NULL REFERENCES rounds (id),
+        duck_id INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        variety TEXT NOT NULL,
+        color TEXT NOT NULL,
+        score INTEGER NOT NULL,
+        rank INTEGER NOT NULL,
+        team INTEGER
+    );
+    CREATE INDEX IF NOT EXISTS round_participants_score ON round_participants (score);
+";
+
+/// A storage actor
+///
+/// Owns the SQLite database of finished rounds, used for match history and leaderboards
+///
+/// Runs on its own arbiter, so database access doesn't block lobbies
+#[derive(Debug)]
+pub struct Storage {
+    pub connection: Connection,
+}
+
+/// A duck's result in a finished round
+#[derive(Debug, Clone, Serialize)]
+pub struct RoundParticipant {
+    pub duck_id: u32,
+    pub name: String,
+    pub variety: String,
+    pub color: String,
+    pub score: u32,
+    /// Team in team mode
+    pub team: Option<u32>,
+}
+
+/// A finished round and its participants, ranked by score, or by team score in team mode
+#[derive(Debug, Clone, Serialize)]
+pub struct RoundRecord {
+    pub lobby_code: String,
+    /// Unix seconds
+    pub started_at: u64,
+    /// Unix seconds
+    pub ended_at: u64,
+    pub duration_secs: u64,
+    pub seed: u64,
+    pub replay_id: Option<String>,
+    pub participants: Vec<RoundParticipant>,
+}
+
+/// A single round result on a leaderboard
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardEntry {
+    pub name: String,
+    pub variety: String,
+    pub color: String,
+    pub score: u32,
+    pub lobby_code: String,
+    /// Unix seconds
+    pub ended_at: u64,
+}
+
+impl Storage {
+    /// Opens the database file, creating it and its tables if needed
+    pub fn open(path: &Path) -> rusqlite::Result<Storage> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(SCHEMA)?;
+        Ok(Storage { connection })
+    }
+
+    /// Saves a finished round with all its participants
+    pub fn insert_round(&mut self, round: &RoundRecord) -> rusqlite::Result<()> {
+        let transaction = self.connection.transaction()?;
+        transaction.execute(
+            "INSERT INTO rounds (lobby_code, started_at, ended_at, duration_secs, seed, replay_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                round.lobby_code,
+                round.started_at,
+                round.ended_at,
+                round.duration_secs,
+                // sqlite integers are signed, so seeds are stored as text
+                round.seed.to_string(),
+                round.replay_id,
+            ],
+        )?;
+        let round_id = transaction.last_insert_rowid();
+        for (rank, participant) in round.participants.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO round_participants (round_id, duck_id, name, variety, color, score, rank, team)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    round_id,
+                    participant.duck_id,
+                    participant.name,
+                    participant.variety,
+                    participant.color,
+                    participant.score,
+                    rank + 1,
+                    participant.team,
+                ],
+            )?;
+        }
+        transaction.commit()
+    }
+
+    /// Returns highest scoring round results that ended at or after the given unix time
+    pub fn leaderboard(&self, since: u64, limit: usize) -> rusqlite::Result<Vec<LeaderboardEntry>> {
+        let mut statement = self.connection.prepare_cached(
+            "SELECT p.name, p.variety, p.color, p.score, r.lobby_code, r.ended_at
+             FROM round_participants p JOIN rounds r ON r.id = p.round_id
+             WHERE r.ended_at >= ?1
+             ORDER BY p.score DESC, r.ended_at ASC
+             LIMIT ?2",
+        )?;
+        let entries = statement.query_map(params![since, limit], |row| {
+            Ok(LeaderboardEntry {
+                name: row.get(0)?,
+                variety: row.get(1)?,
+                color: row.get(2)?,
+                score: row.get(3)?,
+                lobby_code: row.get(4)?,
+                ended_at: row.get(5)?,
+            })
+        })?;
+        entries.collect()
+    }
+
+    /// Returns the most recently finished rounds, newest first
+    pub fn recent_rounds(&self, limit: usize) -> rusqlite::Result<Vec<RoundRecord>> {
+        let mut round_statement = self.connection.prepare_cached(
+            "SELECT id, lobby_code, started_at, ended_at, duration_secs, seed, replay_id
+             FROM rounds ORDER BY ended_at DESC, id DESC LIMIT ?1",
+        )?;
+        let mut participant_statement = self.connection.prepare_cached(
+            "SELECT duck_id, name, variety, color, score, team
+             FROM round_participants WHERE round_id = ?1 ORDER BY rank",
+        )?;
+
+        let rounds = round_statement
+            .query_map(params![limit], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    RoundRecord {
+                        lobby_code: row.get(1)?,
+                        started_at: row.get(2)?,
+                        ended_at: row.get(3)?,
+                        duration_secs: row.get(4)?,
+                        seed: row.get::<_, String>(5)?.parse().unwrap_or_default(),
+                        replay_id: row.get(6)?,
+                        participants: Vec::new(),
+                    },
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        rounds
+            .into_iter()
+            .map(|(round_id, mut round)| {
+                round.participants = participant_statement
+                    .query_map(params![round_id], |row| {
+                        Ok(RoundParticipant {
+                            duck_id: row.get(0)?,
+                            name: row.get(1)?,
+                            variety: row.get(2)?,
+                            color: row.get(3)?,
+                            score: row.get(4)?,
+                            team: row.get(5)?,
+                        })
+                    })?
+                    .collect::<rusqlite::Result<Vec<_>>>()?;
+                Ok(round)
+            })
+            .collect()
+    }
+}
+
+impl Actor for Storage {
+    type Context = Context<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage() -> Storage {
+        let connection = Connection::open_in_memory().unwrap();
+        connection.execute_batch(SCHEMA).unwrap();
+        Storage { connection }
+    }
+
+    fn participant(duck_id: u32, name: &str, score: u32, team: Option<u32>) -> RoundParticipant {
+        RoundParticipant {
+            duck_id,
+            name: name.to_owned(),
+            variety: "mallard".to_owned(),
+            color: "#ffffff".to_owned(),
+            score,
+            team,
+        }
+    }
+
+    fn round(ended_at: u64, participants: Vec<RoundParticipant>) -> RoundRecord {
+        RoundRecord {
+            lobby_code: "POND".to_owned(),
+            started_at: ended_at - 30,
+            ended_at,
+            duration_secs: 30,
+            seed: u64::MAX,
+            replay_id: Some(format!("replay{ended_at}")),
+            participants,
+        }
+    }
+
+    #[test]
+    fn recent_rounds_round_trip_newest_first() {
+        let mut storage = storage();
+        let first = round(1_000, vec![participant(1, "a", 3, None)]);
+        let second = round(
+            2_000,
+            vec![
+                participant(2, "b", 5, Some(1)),
+                participant(1, "a", 7, Some(0)),
+            ],
+        );
+        storage.insert_round(&first).unwrap();
+        storage.insert_round(&second).unwrap();
+
+        let rounds = storage.recent_rounds(10).unwrap();
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].ended_at, 2_000);
+        assert_eq!(rounds[0].seed, u64::MAX);
+        assert_eq!(rounds[0].replay_id.as_deref(), Some("replay2000"));
+        // participants keep the order they were ranked in, not score order
+        let ranked: Vec<_> = rounds[0]
+            .participants
+            .iter()
+            .map(|participant| (participant.duck_id, participant.score, participant.team))
+            .collect();
+        assert_eq!(ranked, [(2, 5, Some(1)), (1, 7, Some(0))]);
+        assert_eq!(rounds[1].participants[0].name, "a");
+
+        assert_eq!(storage.recent_rounds(1).unwrap()[0].ended_at, 2_000);
+    }
+
+    #[test]
+    fn leaderboard_ranks_results_since() {
+        let mut storage = storage();
+        storage
+            .insert_round(&round(1_000, vec![participant(1, "old", 9, None)]))
+            .unwrap();
+        storage
+            .insert_round(&round(
+                2_000,
+                vec![participant(1, "a", 4, None), participant(2, "b", 6, None)],
+            ))
+            .unwrap();
+        storage
+            .insert_round(&round(3_000, vec![participant(1, "c", 6, None)]))
+            .unwrap();
+
+        let names = |entries: Vec<LeaderboardEntry>| -> Vec<String> {
+            entries.into_iter().map(|entry| entry.name).collect()
+        };
+        // ties go to the earlier round
+        assert_eq!(
+            names(storage.leaderboard(0, 10).unwrap()),
+            ["old", "b", "c", "a"]
+        );
+        assert_eq!(
+            names(storage.leaderboard(2_000, 10).unwrap()),
+            ["b", "c", "a"]
+        );
+        assert_eq!(names(storage.leaderboard(2_000, 1).unwrap()), ["b"]);
+        assert!(storage.leaderboard(3_001, 10).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,177 @@
+//! Admin HTTP API
+//!
+//! Lets operators inspect lobbies and control rounds without joining as a duck.
+//! Every request needs an `Authorization: Bearer <admin_token>` header, and the
+//! API responds 404 when no admin token is configured.
+
+use actix::Addr;
+use actix_cors::Cors;
+use actix_web::{
+    dev::Payload, error, http::header, web, Error, FromRequest, HttpRequest, HttpResponse,
+};
+use serde::Deserialize;
+use std::future::{ready, Ready};
+
+use crate::{actors, config::Config, messages};
+
+/// Proof the request carries the configured admin token
+///
+/// Extracting it fails with 404 if the admin API is disabled, or 401 for a wrong token
+pub struct AdminAuth;
+
+impl FromRequest for AdminAuth {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let token = request
+            .app_data::<web::Data<Config>>()
+            .and_then(|config| config.admin_token.clone());
+        let Some(token) = token else {
+            return ready(Err(error::ErrorNotFound("admin api is disabled")));
+        };
+
+        let given = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        ready(match given {
+            Some(given) if constant_time_eq(given.as_bytes(), token.as_bytes()) => Ok(AdminAuth),
+            _ => Err(error::ErrorUnauthorized("invalid admin token")),
+        })
+    }
+}
+
+/// Compares tokens without returning early at the first differing byte
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// Query parameters selecting the lobby an admin request applies to
+#[derive(Deserialize)]
+struct LobbyQuery {
+    lobby: Option<String>,
+    seed: Option<u64>,
+}
+
+/// Returns the game server of the lobby given by `?lobby=CODE`, or the default lobby
+async fn get_lobby(
+    query: &LobbyQuery,
+    lobby_manager: &Addr<actors::LobbyManager>,
+) -> Result<Addr<actors::GameServer>, Error> {
+    let code = match &query.lobby {
+        None => actors::DEFAULT_LOBBY_CODE.to_owned(),
+        Some(code) => actors::normalize_lobby_code(code)
+            .ok_or_else(|| error::ErrorBadRequest("invalid lobby code"))?,
+    };
+    lobby_manager
+        .send(messages::GetLobby { code: code.clone() })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .ok_or_else(|| error::ErrorNotFound(format!("lobby {code} not found")))
+}
+
+/// Responds with the state of every running lobby
+async fn list_lobbies(
+    _: AdminAuth,
+    lobby_manager: web::Data<Addr<actors::LobbyManager>>,
+) -> Result<HttpResponse, Error> {
+    let codes = lobby_manager
+        .send(messages::ListLobbies {})
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    let mut lobbies = Vec::new();
+    for code in codes {
+        let Some(server) = lobby_manager
+            .send(messages::GetLobby { code })
+            .await
+            .map_err(error::ErrorInternalServerError)?
+        else {
+            continue;
+        };
+        // lobbies closing in the meantime are left out
+        if let Ok(status) = server.send(messages::GetLobbyStatus {}).await {
+            lobbies.push(status);
+        }
+    }
+    Ok(HttpResponse::Ok().json(lobbies))
+}
+
+/// Starts a round in the lobby, with `?seed=N` if given
+async fn start_game(
+    _: AdminAuth,
+    query: web::Query<LobbyQuery>,
+    lobby_manager: web::Data<Addr<actors::LobbyManager>>,
+) -> Result<HttpResponse, Error> {
+    let server = get_lobby(&query, &lobby_manager).await?;
+    server
+        .send(messages::StartGame {
+            requester: None,
+            seed: query.seed,
+        })
+        .await
+        .map_err(error::ErrorServiceUnavailable)??;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Ends the running round in the lobby now
+async fn end_game(
+    _: AdminAuth,
+    query: web::Query<LobbyQuery>,
+    lobby_manager: web::Data<Addr<actors::LobbyManager>>,
+) -> Result<HttpResponse, Error> {
+    let server = get_lobby(&query, &lobby_manager).await?;
+    server
+        .send(messages::EndGame {
+            reason: messages::EndReason::Admin,
+        })
+        .await
+        .map_err(error::ErrorServiceUnavailable)??;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Removes a duck from the lobby, disconnecting its player
+async fn kick(
+    _: AdminAuth,
+    id: web::Path<u32>,
+    query: web::Query<LobbyQuery>,
+    lobby_manager: web::Data<Addr<actors::LobbyManager>>,
+) -> Result<HttpResponse, Error> {
+    let server = get_lobby(&query, &lobby_manager).await?;
+    server
+        .send(messages::KickDuck {
+            requester: None,
+            id: id.into_inner(),
+            reason: "kicked by admin".to_owned(),
+        })
+        .await
+        .map_err(error::ErrorServiceUnavailable)??;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Responds with the effective server settings, without the admin token
+async fn get_config(_: AdminAuth, config: web::Data<Config>) -> HttpResponse {
+    HttpResponse::Ok().json(config.get_ref())
+}
+
+/// Registers admin routes under `/admin`
+///
+/// The admin page is served separately, so any origin may call the API with a token
+pub fn configure(service_config: &mut web::ServiceConfig) {
+    service_config.service(
+        web::scope("/admin")
+            .wrap(
+                Cors::default()
+                    .allow_any_origin()
+                    .allowed_methods(["GET", "POST"])
+                    .allowed_header(header::AUTHORIZATION),
+            )
+            .route("/lobbies", web::get().to(list_lobbies))
+            .route("/start", web::post().to(start_game))
+            .route("/end", web::post().to(end_game))
+            .route("/kick/{id}", web::post().to(kick))
+            .route("/config", web::get().to(get_config)),
+    );
+}
@@ -0,0 +1,286 @@
+//! Bread falling into the pond
+//!
+//! Each bread has a kind deciding its points, size and how it falls. Lobbies
+//! choose how likely each kind is to spawn with weights.
+
+use crate::protos::protos::protos;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::{str::FromStr, time::Duration};
+
+/// Height bread is dropped from
+const BREAD_SPAWN_HEIGHT: f32 = 10.0;
+/// Lowest height bread rests at on the water
+const BREAD_REST_HEIGHT: f32 = 0.1;
+/// Speed multiplier of ducks that ate stale bread
+pub const STALE_SLOWDOWN: f32 = 0.5;
+/// How long ducks stay slowed after eating stale bread
+pub const STALE_SLOW_DURATION: Duration = Duration::from_secs(3);
+
+/// Kind of a bread
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreadKind {
+    /// Plain bread worth a point
+    Crust,
+    /// Rare, large loaf drifting down slowly, worth 5 points
+    GoldenLoaf,
+    /// Heavy bread dropping fast, worth a point but slows the duck eating it
+    Stale,
+}
+
+impl BreadKind {
+    /// Returns points a duck scores for eating the bread
+    pub fn value(&self) -> u32 {
+        match self {
+            BreadKind::Crust => 1,
+            BreadKind::GoldenLoaf => 5,
+            BreadKind::Stale => 1,
+        }
+    }
+
+    /// Returns half extents of the box used for pickups
+    pub fn size(&self) -> (f32, f32, f32) {
+        match self {
+            BreadKind::Crust => (0.2, 0.2, 0.2),
+            BreadKind::GoldenLoaf => (0.35, 0.25, 0.35),
+            BreadKind::Stale => (0.2, 0.2, 0.2),
+        }
+    }
+
+    /// Returns downwards acceleration of the bread while falling
+    fn gravity(&self) -> f32 {
+        match self {
+            BreadKind::Crust => -5.0,
+            BreadKind::GoldenLoaf => -1.5,
+            BreadKind::Stale => -12.0,
+        }
+    }
+
+    pub fn to_proto(self) -> protos::BreadKind {
+        match self {
+            BreadKind::Crust => protos::BreadKind::CRUST,
+            BreadKind::GoldenLoaf => protos::BreadKind::GOLDEN_LOAF,
+            BreadKind::Stale => protos::BreadKind::STALE,
+        }
+    }
+}
+
+/// A bread in the pond
+#[derive(Debug, Clone)]
+pub struct Bread {
+    pub kind: BreadKind,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Bread {
+    /// Creates bread of the given kind dropped above a point of the pond
+    pub fn new(kind: BreadKind, x: f32, z: f32) -> Self {
+        Bread {
+            kind,
+            x,
+            y: BREAD_SPAWN_HEIGHT,
+            z,
+        }
+    }
+
+    pub fn position(&self) -> (f32, f32, f32) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Moves the bread down by one tick until it rests on the water
+    pub fn fall(&mut self, delta_time: f32) {
+        let gravity = self.kind.gravity();
+        // sqrt(v^2 - 2as) = u
+        let velocity = -f32::sqrt(f32::abs(2.0 * gravity * (BREAD_SPAWN_HEIGHT - self.y)));
+        self.y += velocity * delta_time + 0.5 * gravity * delta_time.powi(2);
+        self.y = self.y.max(BREAD_REST_HEIGHT);
+    }
+
+    pub fn to_proto(&self, id: u32) -> protos::Bread {
+        protos::Bread {
+            id,
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            kind: self.kind.to_proto().into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Relative chances of each bread kind spawning
+///
+/// A kind with weight 0, or left out of the config, never spawns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BreadWeights {
+    #[serde(default)]
+    pub crust: u32,
+    #[serde(default)]
+    pub golden_loaf: u32,
+    #[serde(default)]
+    pub stale: u32,
+}
+
+impl Default for BreadWeights {
+    fn default() -> Self {
+        BreadWeights {
+            crust: 8,
+            golden_loaf: 1,
+            stale: 1,
+        }
+    }
+}
+
+impl BreadWeights {
+    pub fn total(&self) -> u32 {
+        self.crust
+            .saturating_add(self.golden_loaf)
+            .saturating_add(self.stale)
+    }
+
+    /// Picks a kind at random according to the weights
+    ///
+    /// Weights must not all be 0
+    pub fn pick(&self, rng: &mut impl Rng) -> BreadKind {
+        let mut roll = rng.gen_range(0..self.total());
+        for (kind, weight) in [
+            (BreadKind::Crust, self.crust),
+            (BreadKind::GoldenLoaf, self.golden_loaf),
+            (BreadKind::Stale, self.stale),
+        ] {
+            if roll < weight {
+                return kind;
+            }
+            roll -= weight;
+        }
+        unreachable!("roll is below the total weight")
+    }
+
+    pub fn to_proto(self) -> protos::BreadWeights {
+        protos::BreadWeights {
+            crust: self.crust,
+            golden_loaf: self.golden_loaf,
+            stale: self.stale,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<protos::BreadWeights> for BreadWeights {
+    fn from(weights: protos::BreadWeights) -> Self {
+        BreadWeights {
+            crust: weights.crust,
+            golden_loaf: weights.golden_loaf,
+            stale: weights.stale,
+        }
+    }
+}
+
+impl FromStr for BreadWeights {
+    type Err = String;
+
+    /// Parses comma separated weights like "crust=8,golden_loaf=1,stale=1"
+    ///
+    /// Kinds left out get weight 0
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights = BreadWeights {
+            crust: 0,
+            golden_loaf: 0,
+            stale: 0,
+        };
+        for entry in s
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let (kind, weight) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("invalid bread weight: {entry}"))?;
+            let weight = weight
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid bread weight: {entry}"))?;
+            match kind.trim() {
+                "crust" => weights.crust = weight,
+                "golden_loaf" => weights.golden_loaf = weight,
+                "stale" => weights.stale = weight,
+                kind => return Err(format!("unknown bread kind: {kind}")),
+            }
+        }
+        Ok(weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn weights(crust: u32, golden_loaf: u32, stale: u32) -> BreadWeights {
+        BreadWeights {
+            crust,
+            golden_loaf,
+            stale,
+        }
+    }
+
+    #[test]
+    fn picks_only_kinds_with_weight() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let only_golden = weights(0, 3, 0);
+        assert!((0..100).all(|_| only_golden.pick(&mut rng) == BreadKind::GoldenLoaf));
+        let no_golden = weights(1, 0, 1);
+        assert!((0..100).all(|_| no_golden.pick(&mut rng) != BreadKind::GoldenLoaf));
+    }
+
+    #[test]
+    fn picks_in_proportion_to_weights() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        let weights = weights(6, 3, 1);
+        let mut counts = [0i32; 3];
+        for _ in 0..10_000 {
+            counts[weights.pick(&mut rng) as usize] += 1;
+        }
+        for (count, expected) in counts.into_iter().zip([6_000, 3_000, 1_000]) {
+            assert!(
+                (count - expected).abs() < 300,
+                "{counts:?} too far from 6:3:1"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_weights() {
+        assert_eq!(
+            "crust=8,golden_loaf=1,stale=1".parse(),
+            Ok(BreadWeights::default())
+        );
+        assert_eq!(" stale = 2 , crust=3 ".parse(), Ok(weights(3, 0, 2)));
+        assert_eq!("".parse(), Ok(weights(0, 0, 0)));
+    }
+
+    #[test]
+    fn rejects_invalid_weights() {
+        for text in [
+            "crust",
+            "crust=-1",
+            "crust=lots",
+            "rye=1",
+            "crust=1;stale=1",
+        ] {
+            assert!(
+                text.parse::<BreadWeights>().is_err(),
+                "{text:?} should fail"
+            );
+        }
+    }
+
+    #[test]
+    fn total_saturates() {
+        assert_eq!(weights(u32::MAX, 1, 1).total(), u32::MAX);
+    }
+}
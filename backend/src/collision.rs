@@ -0,0 +1,43 @@
+//! Collisions between ducks
+//!
+//! Ducks are circles on the water. Ducks that overlap are pushed apart, or kept
+//! from moving into each other when clients drive movement, and each pair
+//! starting to touch is reported to clients as a collision.
+
+use crate::protos::protos::protos;
+
+/// Radius of the circle a duck takes up on the water
+pub const DUCK_RADIUS: f32 = 0.5;
+/// Height difference above which ducks pass over each other, e.g. while jumping
+pub const DUCK_HEIGHT: f32 = 1.0;
+/// Gap below which ducks count as touching, so ducks kept apart still collide
+pub const CONTACT_TOLERANCE: f32 = 0.05;
+/// Points a bumping duck steals when bumping steals bread
+pub const BUMP_STEAL_POINTS: u32 = 1;
+
+/// Two ducks starting to touch
+#[derive(Debug, Clone)]
+pub struct DuckCollision {
+    pub duck_ids: (u32, u32),
+    /// Point of contact between the ducks
+    pub x: f32,
+    pub z: f32,
+    /// Duck that swam into the other, if either did
+    pub bumper_id: Option<u32>,
+    /// Points the bumper stole from the other duck
+    pub stolen_points: u32,
+}
+
+impl DuckCollision {
+    pub fn to_proto(&self) -> protos::DuckCollision {
+        protos::DuckCollision {
+            duck_a: self.duck_ids.0,
+            duck_b: self.duck_ids.1,
+            x: self.x,
+            z: self.z,
+            bumper_id: self.bumper_id,
+            stolen_points: self.stolen_points,
+            ..Default::default()
+        }
+    }
+}
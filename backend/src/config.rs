@@ -0,0 +1,352 @@
+//! Server configuration
+//!
+//! Values are read from an optional TOML file, then overridden by environment
+//! variables (including ones from `.env`), falling back to defaults.
+
+use crate::{bread::BreadWeights, vote::VoteThreshold};
+use serde::{Deserialize, Serialize};
+use std::{fmt, path::PathBuf, str::FromStr, time::Duration};
+
+/// Most teams a lobby can be split into
+const MAX_TEAM_COUNT: u32 = 8;
+
+/// Effective server settings, shared by all actors
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Address to bind to, defaults to the machine's local ip
+    pub host: Option<String>,
+    pub port: u16,
+    pub workers: usize,
+    pub tick_rate: u32,
+    pub update_sync_interval_ms: u64,
+    pub bread_spawn_per_second: f32,
+    pub bread_limit: usize,
+    /// Ends rounds once all bread is eaten, with `bread_limit` bread spawned per round
+    pub end_when_all_bread_eaten: bool,
+    /// Default chances of each bread kind spawning
+    pub bread_weights: BreadWeights,
+    /// Average power-ups spawned per second during rounds, power-ups are off if 0
+    pub power_up_spawn_per_second: f32,
+    /// Most power-ups floating in the pond at once
+    pub power_up_limit: usize,
+    pub heartbeat_interval_secs: u64,
+    pub client_timeout_secs: u64,
+    /// Countdown before each round starts
+    pub countdown_secs: u64,
+    pub game_duration_secs: u64,
+    /// Time the podium is shown after each round, before waiting for the next
+    pub podium_secs: u64,
+    /// Longest round duration lobbies may choose
+    pub max_game_duration_secs: u64,
+    /// Highest bread spawn rate lobbies may choose
+    pub max_bread_spawn_per_second: f32,
+    /// Highest bread limit lobbies may choose
+    pub max_bread_limit: usize,
+    /// Maps lobbies may choose, the first is the default
+    pub maps: Vec<String>,
+    /// Most lobbies running at once, joining a new lobby fails beyond this
+    pub max_lobbies: usize,
+    pub lobby_idle_timeout_secs: u64,
+    pub resume_grace_period_secs: u64,
+    /// Hands ids of ducks that left to new ducks, keeping ids small
+    pub recycle_duck_ids: bool,
+    /// Seed for every round's rng instead of a random one, to reproduce a logged round
+    pub round_seed: Option<u64>,
+    /// Directory rounds are recorded to as replays, recording is off if unset
+    pub replay_dir: Option<PathBuf>,
+    /// SQLite database of finished rounds
+    pub database_path: PathBuf,
+    /// Bearer token required by the admin API, which is disabled if unset
+    #[serde(skip_serializing)]
+    pub admin_token: Option<String>,
+    pub vote_threshold: VoteThreshold,
+    /// Number of teams ducks are split into, team mode is off if 0
+    pub team_count: u32,
+    /// Ducks bumping into others during rounds steal a point from them
+    pub bump_steals_bread: bool,
+    pub authoritative_movement: bool,
+    pub legacy_protocol: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            host: None,
+            port: 4421,
+            workers: 2,
+            tick_rate: 60,
+            update_sync_interval_ms: 50,
+            bread_spawn_per_second: 3.0,
+            bread_limit: 500,
+            end_when_all_bread_eaten: false,
+            bread_weights: BreadWeights::default(),
+            power_up_spawn_per_second: 0.2,
+            power_up_limit: 3,
+            heartbeat_interval_secs: 5,
+            client_timeout_secs: 10,
+            countdown_secs: 3,
+            game_duration_secs: 30,
+            podium_secs: 5,
+            max_game_duration_secs: 300,
+            max_bread_spawn_per_second: 20.0,
+            max_bread_limit: 1000,
+            maps: vec!["pond".to_owned()],
+            max_lobbies: 100,
+            lobby_idle_timeout_secs: 30,
+            resume_grace_period_secs: 30,
+            recycle_duck_ids: true,
+            round_seed: None,
+            replay_dir: None,
+            database_path: PathBuf::from("duck_simulator.sqlite3"),
+            admin_token: None,
+            vote_threshold: VoteThreshold::Majority,
+            team_count: 0,
+            bump_steals_bread: false,
+            authoritative_movement: false,
+            // TODO remove legacy text protocol next release
+            legacy_protocol: true,
+        }
+    }
+}
+
+/// Reason configuration couldn't be loaded
+#[derive(Debug)]
+pub struct ConfigError(String);
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid config: {}", self.0)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Loads config from the TOML file if given, then environment variables and `.env`
+    ///
+    /// Without a path, the file is taken from `CONFIG_FILE` if set
+    pub fn load(path: Option<PathBuf>) -> Result<Config, ConfigError> {
+        // variables already set in the environment take precedence over .env
+        if let Err(e) = dotenvy::dotenv() {
+            if !e.not_found() {
+                return Err(ConfigError(format!(".env: {e}")));
+            }
+        }
+
+        let path = path.or_else(|| std::env::var_os("CONFIG_FILE").map(PathBuf::from));
+        let mut config = match path {
+            Some(path) => {
+                let text = std::fs::read_to_string(&path)
+                    .map_err(|e| ConfigError(format!("{}: {e}", path.display())))?;
+                toml::from_str(&text)
+                    .map_err(|e| ConfigError(format!("{}: {e}", path.display())))?
+            }
+            None => Config::default(),
+        };
+
+        config.apply_env()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Overrides values with environment variables of the same name in uppercase
+    ///
+    /// Flags are `true`/`false` or `1`/`0`
+    fn apply_env(&mut self) -> Result<(), ConfigError> {
+        fn var<T: FromStr>(name: &str, value: &mut T) -> Result<(), ConfigError>
+        where
+            T::Err: fmt::Display,
+        {
+            if let Ok(text) = std::env::var(name) {
+                *value = text
+                    .trim()
+                    .parse()
+                    .map_err(|e| ConfigError(format!("{name}={text}: {e}")))?;
+            }
+            Ok(())
+        }
+        fn flag(name: &str, value: &mut bool) -> Result<(), ConfigError> {
+            if let Ok(text) = std::env::var(name) {
+                *value = match text.trim() {
+                    "1" => true,
+                    "0" => false,
+                    flag => flag
+                        .parse()
+                        .map_err(|e| ConfigError(format!("{name}={text}: {e}")))?,
+                };
+            }
+            Ok(())
+        }
+
+        if let Ok(host) = std::env::var("HOST") {
+            self.host = Some(host);
+        }
+        var("PORT", &mut self.port)?;
+        var("WORKERS", &mut self.workers)?;
+        var("TICK_RATE", &mut self.tick_rate)?;
+        var("UPDATE_SYNC_INTERVAL_MS", &mut self.update_sync_interval_ms)?;
+        var("BREAD_SPAWN_PER_SECOND", &mut self.bread_spawn_per_second)?;
+        var("BREAD_LIMIT", &mut self.bread_limit)?;
+        var("BREAD_WEIGHTS", &mut self.bread_weights)?;
+        var(
+            "POWER_UP_SPAWN_PER_SECOND",
+            &mut self.power_up_spawn_per_second,
+        )?;
+        var("POWER_UP_LIMIT", &mut self.power_up_limit)?;
+        var("HEARTBEAT_INTERVAL_SECS", &mut self.heartbeat_interval_secs)?;
+        var("CLIENT_TIMEOUT_SECS", &mut self.client_timeout_secs)?;
+        var("COUNTDOWN_SECS", &mut self.countdown_secs)?;
+        var("GAME_DURATION_SECS", &mut self.game_duration_secs)?;
+        var("PODIUM_SECS", &mut self.podium_secs)?;
+        var("MAX_GAME_DURATION_SECS", &mut self.max_game_duration_secs)?;
+        var(
+            "MAX_BREAD_SPAWN_PER_SECOND",
+            &mut self.max_bread_spawn_per_second,
+        )?;
+        var("MAX_BREAD_LIMIT", &mut self.max_bread_limit)?;
+        if let Ok(text) = std::env::var("MAPS") {
+            self.maps = text.split(',').map(|map| map.trim().to_owned()).collect();
+        }
+        var("MAX_LOBBIES", &mut self.max_lobbies)?;
+        var("LOBBY_IDLE_TIMEOUT_SECS", &mut self.lobby_idle_timeout_secs)?;
+        var(
+            "RESUME_GRACE_PERIOD_SECS",
+            &mut self.resume_grace_period_secs,
+        )?;
+        if let Ok(text) = std::env::var("ROUND_SEED") {
+            let seed = text
+                .trim()
+                .parse()
+                .map_err(|e| ConfigError(format!("ROUND_SEED={text}: {e}")))?;
+            self.round_seed = Some(seed);
+        }
+        if let Some(directory) = std::env::var_os("REPLAY_DIR") {
+            self.replay_dir = Some(PathBuf::from(directory));
+        }
+        if let Some(path) = std::env::var_os("DATABASE_PATH") {
+            self.database_path = PathBuf::from(path);
+        }
+        if let Ok(token) = std::env::var("ADMIN_TOKEN") {
+            self.admin_token = Some(token);
+        }
+        var("VOTE_THRESHOLD", &mut self.vote_threshold)?;
+        var("TEAM_COUNT", &mut self.team_count)?;
+        flag(
+            "END_WHEN_ALL_BREAD_EATEN",
+            &mut self.end_when_all_bread_eaten,
+        )?;
+        flag("BUMP_STEALS_BREAD", &mut self.bump_steals_bread)?;
+        flag("RECYCLE_DUCK_IDS", &mut self.recycle_duck_ids)?;
+        flag("AUTHORITATIVE_MOVEMENT", &mut self.authoritative_movement)?;
+        flag("LEGACY_PROTOCOL", &mut self.legacy_protocol)?;
+        Ok(())
+    }
+
+    /// Checks values are usable by the server
+    fn validate(&self) -> Result<(), ConfigError> {
+        let check = |valid: bool, message: &str| match valid {
+            true => Ok(()),
+            false => Err(ConfigError(message.to_owned())),
+        };
+
+        check(self.workers > 0, "workers must be at least 1")?;
+        check(self.max_lobbies > 0, "max_lobbies must be at least 1")?;
+        check(
+            (1..=1000).contains(&self.tick_rate),
+            "tick_rate must be between 1 and 1000",
+        )?;
+        check(
+            self.update_sync_interval_ms > 0,
+            "update_sync_interval_ms must be positive",
+        )?;
+        check(
+            self.bread_spawn_per_second.is_finite() && self.bread_spawn_per_second >= 0.0,
+            "bread_spawn_per_second must be a non-negative number",
+        )?;
+        check(
+            self.bread_weights.total() > 0,
+            "bread_weights must not all be 0",
+        )?;
+        check(
+            self.power_up_spawn_per_second.is_finite() && self.power_up_spawn_per_second >= 0.0,
+            "power_up_spawn_per_second must be a non-negative number",
+        )?;
+        check(
+            self.heartbeat_interval_secs > 0,
+            "heartbeat_interval_secs must be positive",
+        )?;
+        check(
+            self.client_timeout_secs > self.heartbeat_interval_secs,
+            "client_timeout_secs must be longer than heartbeat_interval_secs",
+        )?;
+        check(
+            self.admin_token
+                .as_ref()
+                .is_none_or(|token| !token.is_empty()),
+            "admin_token must not be empty",
+        )?;
+        check(
+            self.game_duration_secs > 0,
+            "game_duration_secs must be positive",
+        )?;
+        check(
+            self.game_duration_secs <= self.max_game_duration_secs,
+            "game_duration_secs must be at most max_game_duration_secs",
+        )?;
+        check(
+            self.bread_spawn_per_second <= self.max_bread_spawn_per_second,
+            "bread_spawn_per_second must be at most max_bread_spawn_per_second",
+        )?;
+        check(
+            self.bread_limit <= self.max_bread_limit,
+            "bread_limit must be at most max_bread_limit",
+        )?;
+        check(
+            !self.end_when_all_bread_eaten || self.bread_limit > 0,
+            "bread_limit must be at least 1 when rounds end with all bread eaten",
+        )?;
+        check(
+            self.team_count == 0 || (2..=MAX_TEAM_COUNT).contains(&self.team_count),
+            "team_count must be 0, or between 2 and 8",
+        )?;
+        check(
+            !self.maps.is_empty() && self.maps.iter().all(|map| !map.is_empty()),
+            "maps must list at least one map, and map names must not be empty",
+        )?;
+        Ok(())
+    }
+
+    /// Returns simulated time per tick
+    pub fn tick_interval(&self) -> Duration {
+        Duration::from_secs(1) / self.tick_rate
+    }
+
+    pub fn update_sync_interval(&self) -> Duration {
+        Duration::from_millis(self.update_sync_interval_ms)
+    }
+
+    pub fn heartbeat_interval(&self) -> Duration {
+        Duration::from_secs(self.heartbeat_interval_secs)
+    }
+
+    pub fn client_timeout(&self) -> Duration {
+        Duration::from_secs(self.client_timeout_secs)
+    }
+
+    pub fn countdown(&self) -> Duration {
+        Duration::from_secs(self.countdown_secs)
+    }
+
+    pub fn podium(&self) -> Duration {
+        Duration::from_secs(self.podium_secs)
+    }
+
+    pub fn lobby_idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.lobby_idle_timeout_secs)
+    }
+
+    pub fn resume_grace_period(&self) -> Duration {
+        Duration::from_secs(self.resume_grace_period_secs)
+    }
+}
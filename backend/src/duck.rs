@@ -1,3 +1,55 @@
+use crate::{
+    bread::{STALE_SLOWDOWN, STALE_SLOW_DURATION},
+    power_up::{PowerUpKind, SPEED_BOOST},
+};
+use std::{
+    collections::HashMap,
+    f32::consts::PI,
+    time::{Duration, Instant},
+};
+
+/// Radius of the pond ducks swim in and bread falls into
+pub const POND_RADIUS: f32 = 11.5;
+/// Distance a duck moves per second at full input
+pub const DUCK_SPEED: f32 = 3.0;
+const DUCK_JUMP_VELOCITY: f32 = 4.0;
+const GRAVITY: f32 = -9.8;
+
+/// Multiplier on the duck's speed allowed before a client sent move counts as too fast
+const SPEED_TOLERANCE: f32 = 1.5;
+/// Extra distance allowed per move to absorb network jitter and bunched packets
+const DISTANCE_TOLERANCE: f32 = 0.5;
+/// Distance outside the pond allowed before a position counts as out of bounds
+const BOUNDS_TOLERANCE: f32 = 0.5;
+const MIN_Y: f32 = -0.5;
+const MAX_Y: f32 = 1.5;
+
+/// Reason a client sent position was rejected or clamped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovementViolation {
+    NonFinite,
+    OutOfBounds,
+    TooFast,
+}
+
+impl std::fmt::Display for MovementViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MovementViolation::NonFinite => write!(f, "non-finite position or rotation"),
+            MovementViolation::OutOfBounds => write!(f, "position outside pond"),
+            MovementViolation::TooFast => write!(f, "moved faster than max speed"),
+        }
+    }
+}
+
+/// Latest movement intent sent by a duck's client
+#[derive(Debug, Default, Clone)]
+pub struct DuckInput {
+    pub move_x: f32,
+    pub move_z: f32,
+    pub jump: bool,
+}
+
 /// Duck class
 #[derive(Debug)]
 pub struct Duck {
@@ -5,10 +57,22 @@ pub struct Duck {
     pub y: f32,
     pub z: f32,
     pub rotation_radians: f32,
+    pub velocity_y: f32,
     pub score: u32,
+    pub input: DuckInput,
+    pub input_sequence: u32,
+    pub last_update_time: Option<Instant>,
+    /// Position from the client's last update, before it was kept clear of other ducks
+    pub last_update_position: (f32, f32),
     pub name: Option<String>,
     pub variety: Option<String>,
     pub color: Option<String>,
+    /// Team the duck scores for in team mode
+    pub team: Option<u32>,
+    /// Active power-up effects and their time left
+    pub effects: HashMap<PowerUpKind, Duration>,
+    /// Time left slowed from eating stale bread
+    pub slowed: Duration,
 }
 
 impl Duck {
@@ -18,10 +82,246 @@ impl Duck {
             y: 0.0,
             z: 0.0,
             rotation_radians: 0.0,
+            velocity_y: 0.0,
             score: 0,
+            input: DuckInput::default(),
+            input_sequence: 0,
+            last_update_time: None,
+            last_update_position: (0.0, 0.0),
             name: None,
             variety: None,
             color: None,
+            team: None,
+            effects: HashMap::new(),
+            slowed: Duration::ZERO,
+        }
+    }
+
+    /// Returns whether the duck has the power-up effect
+    pub fn has_effect(&self, kind: PowerUpKind) -> bool {
+        self.effects.contains_key(&kind)
+    }
+
+    /// Gives the duck a power-up effect, restarting its timer if already active
+    pub fn add_effect(&mut self, kind: PowerUpKind) {
+        self.effects.insert(kind, kind.duration());
+    }
+
+    /// Slows the duck down after eating stale bread, restarting the timer if already slowed
+    pub fn slow_down(&mut self) {
+        self.slowed = STALE_SLOW_DURATION;
+    }
+
+    /// Counts down active effects and slowdown, removing effects that ran out
+    pub fn tick_effects(&mut self, delta_time: Duration) {
+        self.effects.retain(|_, remaining| {
+            *remaining = remaining.saturating_sub(delta_time);
+            !remaining.is_zero()
+        });
+        self.slowed = self.slowed.saturating_sub(delta_time);
+    }
+
+    /// Returns distance the duck moves per second at full input
+    pub fn speed(&self) -> f32 {
+        let mut speed = DUCK_SPEED;
+        if self.has_effect(PowerUpKind::Speed) {
+            speed *= SPEED_BOOST;
+        }
+        if !self.slowed.is_zero() {
+            speed *= STALE_SLOWDOWN;
+        }
+        speed
+    }
+
+    /// Moves duck by its latest input over delta_time, keeping it inside the pond
+    ///
+    /// Duck faces the direction it moves in, and can only jump while on the water
+    pub fn apply_input(&mut self, delta_time: f32) {
+        let (move_x, move_z) = (self.input.move_x, self.input.move_z);
+        if move_x != 0.0 || move_z != 0.0 {
+            self.x += move_x * self.speed() * delta_time;
+            self.z += move_z * self.speed() * delta_time;
+            self.rotation_radians = f32::atan2(move_x, move_z);
         }
+
+        self.keep_in_pond();
+
+        if self.input.jump && self.y <= 0.0 {
+            self.velocity_y = DUCK_JUMP_VELOCITY;
+        }
+        self.input.jump = false;
+
+        if self.y > 0.0 || self.velocity_y > 0.0 {
+            self.y += self.velocity_y * delta_time + 0.5 * GRAVITY * delta_time.powi(2);
+            self.velocity_y += GRAVITY * delta_time;
+        }
+        if self.y <= 0.0 {
+            self.y = 0.0;
+            self.velocity_y = 0.0;
+        }
+    }
+
+    /// Moves the duck back to the edge of the pond if it's outside
+    pub fn keep_in_pond(&mut self) {
+        let distance = f32::hypot(self.x, self.z);
+        if distance > POND_RADIUS {
+            self.x *= POND_RADIUS / distance;
+            self.z *= POND_RADIUS / distance;
+        }
+    }
+
+    /// Applies a client sent position and rotation, clamping impossible moves
+    ///
+    /// Non-finite updates are rejected entirely, positions are clamped inside the
+    /// pond and to max speed since the previous update, and rotation is wrapped
+    ///
+    /// Returns the violation if the update had to be rejected or clamped
+    pub fn apply_update(&mut self, update: &Duck, now: Instant) -> Option<MovementViolation> {
+        if !(update.x.is_finite()
+            && update.y.is_finite()
+            && update.z.is_finite()
+            && update.rotation_radians.is_finite())
+        {
+            return Some(MovementViolation::NonFinite);
+        }
+
+        let mut violation = None;
+        let (mut x, mut z) = (update.x, update.z);
+        let mut y = update.y;
+
+        let distance = f32::hypot(x, z);
+        if distance > POND_RADIUS {
+            if distance > POND_RADIUS + BOUNDS_TOLERANCE {
+                violation = Some(MovementViolation::OutOfBounds);
+            }
+            x *= POND_RADIUS / distance;
+            z *= POND_RADIUS / distance;
+        }
+        if !(MIN_Y..=MAX_Y).contains(&y) {
+            violation = Some(MovementViolation::OutOfBounds);
+            y = y.clamp(MIN_Y, MAX_Y);
+        }
+
+        // speed is only checked against a previous update from the client
+        if let Some(last_update_time) = self.last_update_time {
+            let elapsed = now.duration_since(last_update_time).as_secs_f32();
+            let max_distance = self.speed() * SPEED_TOLERANCE * elapsed + DISTANCE_TOLERANCE;
+            let (last_x, last_z) = self.last_update_position;
+            let (dx, dz) = (x - last_x, z - last_z);
+            let moved = f32::hypot(dx, dz);
+            if moved > max_distance {
+                violation = Some(MovementViolation::TooFast);
+                x = last_x + dx * max_distance / moved;
+                z = last_z + dz * max_distance / moved;
+            }
+        }
+
+        self.x = x;
+        self.y = y;
+        self.z = z;
+        self.last_update_position = (x, z);
+        self.rotation_radians = (update.rotation_radians + PI).rem_euclid(2.0 * PI) - PI;
+        self.last_update_time = Some(now);
+
+        violation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(x: f32, y: f32, z: f32, rotation_radians: f32) -> Duck {
+        Duck {
+            x,
+            y,
+            z,
+            rotation_radians,
+            ..Duck::new()
+        }
+    }
+
+    #[test]
+    fn rejects_non_finite_updates() {
+        let mut duck = Duck::new();
+        for bad in [
+            update(f32::NAN, 0.0, 0.0, 0.0),
+            update(0.0, f32::INFINITY, 0.0, 0.0),
+            update(0.0, 0.0, f32::NEG_INFINITY, 0.0),
+            update(0.0, 0.0, 0.0, f32::NAN),
+        ] {
+            assert_eq!(
+                duck.apply_update(&bad, Instant::now()),
+                Some(MovementViolation::NonFinite)
+            );
+        }
+        assert_eq!((duck.x, duck.y, duck.z), (0.0, 0.0, 0.0));
+        assert!(duck.last_update_time.is_none());
+    }
+
+    #[test]
+    fn clamps_into_pond_with_tolerance() {
+        let mut duck = Duck::new();
+        let just_outside = POND_RADIUS + BOUNDS_TOLERANCE / 2.0;
+        assert_eq!(
+            duck.apply_update(&update(just_outside, 0.0, 0.0, 0.0), Instant::now()),
+            None
+        );
+        assert_eq!(duck.x, POND_RADIUS);
+
+        let mut duck = Duck::new();
+        assert_eq!(
+            duck.apply_update(&update(0.0, 0.0, -20.0, 0.0), Instant::now()),
+            Some(MovementViolation::OutOfBounds)
+        );
+        assert_eq!(duck.z, -POND_RADIUS);
+
+        let mut duck = Duck::new();
+        assert_eq!(
+            duck.apply_update(&update(0.0, 5.0, 0.0, 0.0), Instant::now()),
+            Some(MovementViolation::OutOfBounds)
+        );
+        assert_eq!(duck.y, MAX_Y);
+    }
+
+    #[test]
+    fn first_update_skips_speed_check() {
+        let mut duck = Duck::new();
+        assert_eq!(
+            duck.apply_update(&update(10.0, 0.0, 0.0, 0.0), Instant::now()),
+            None
+        );
+        assert_eq!(duck.x, 10.0);
+    }
+
+    #[test]
+    fn clamps_speed_from_last_update_position() {
+        let start = Instant::now();
+        let mut duck = Duck::new();
+        duck.apply_update(&update(0.0, 0.0, 0.0, 0.0), start);
+        // kept clear of another duck after the update
+        duck.x = -1.0;
+
+        let now = start + Duration::from_secs(1);
+        let max_distance = DUCK_SPEED * SPEED_TOLERANCE + DISTANCE_TOLERANCE;
+        assert_eq!(
+            duck.apply_update(&update(max_distance - 0.1, 0.0, 0.0, 0.0), now),
+            None
+        );
+
+        let later = now + Duration::from_secs(1);
+        assert_eq!(
+            duck.apply_update(&update(-10.0, 0.0, 0.0, 0.0), later),
+            Some(MovementViolation::TooFast)
+        );
+        assert!((duck.x - (max_distance - 0.1 - max_distance)).abs() < 1e-4);
+        assert_eq!(duck.last_update_position, (duck.x, duck.z));
+    }
+
+    #[test]
+    fn wraps_rotation() {
+        let mut duck = Duck::new();
+        duck.apply_update(&update(0.0, 0.0, 0.0, 3.0 * PI / 2.0), Instant::now());
+        assert!((duck.rotation_radians + PI / 2.0).abs() < 1e-4);
     }
 }
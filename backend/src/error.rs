@@ -0,0 +1,91 @@
+use crate::phase::Phase;
+use actix_web::{http::StatusCode, ResponseError};
+use std::fmt;
+
+/// Errors from handling client messages and updating game state
+///
+/// Errors caused by a client are reported back to it with `cast:error`
+#[derive(Debug)]
+pub enum GameError {
+    /// Binary frame couldn't be parsed as the expected proto
+    MalformedFrame(protobuf::Error),
+    /// Text frame wasn't a known message, or was missing fields
+    MalformedText,
+    /// Frame type isn't used by the client's protocol
+    UnexpectedFrame,
+    /// Message requires the client to have joined a lobby first
+    NotInGame,
+    /// Client tried to join or resume a duck after already doing so
+    AlreadyInGame,
+    /// Message refers to a duck that isn't in the lobby
+    DuckNotFound(u32),
+    /// Message isn't allowed in the lobby's current phase
+    WrongPhase(Phase),
+    /// Message is only allowed from the lobby's host
+    NotHost,
+    /// Requested game settings are outside the server's bounds
+    InvalidSettings(String),
+    /// Duck's name, variety or color can't be sent to other clients
+    InvalidDuck(String),
+    /// No running lobby has the given code
+    LobbyNotFound(String),
+    /// Resume token doesn't belong to any duck in the lobby
+    InvalidResumeToken,
+    /// Lobby's game server stopped before handling the message
+    LobbyUnavailable,
+    /// Player stopped before its join or resume was handled
+    PlayerDisconnected,
+    /// System clock is before the unix epoch or went backwards
+    Clock(std::time::SystemTimeError),
+    /// Outgoing message couldn't be serialized
+    Serialize(protobuf::Error),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::MalformedFrame(e) => write!(f, "malformed binary frame: {e}"),
+            GameError::MalformedText => write!(f, "malformed text message"),
+            GameError::UnexpectedFrame => write!(f, "unexpected frame for protocol"),
+            GameError::NotInGame => write!(f, "not in a game"),
+            GameError::AlreadyInGame => write!(f, "already in a game"),
+            GameError::DuckNotFound(id) => write!(f, "duck {id} not found"),
+            GameError::WrongPhase(phase) => write!(f, "not allowed in {phase} phase"),
+            GameError::NotHost => write!(f, "only the host can do that"),
+            GameError::InvalidSettings(message) => write!(f, "invalid settings: {message}"),
+            GameError::InvalidDuck(message) => write!(f, "invalid duck: {message}"),
+            GameError::LobbyNotFound(code) => write!(f, "lobby {code} not found"),
+            GameError::InvalidResumeToken => write!(f, "invalid resume token"),
+            GameError::LobbyUnavailable => write!(f, "lobby unavailable"),
+            GameError::PlayerDisconnected => write!(f, "player disconnected"),
+            GameError::Clock(e) => write!(f, "clock error: {e}"),
+            GameError::Serialize(e) => write!(f, "failed to serialize message: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+/// Status of admin API responses failing with the error
+impl ResponseError for GameError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            GameError::DuckNotFound(_) | GameError::LobbyNotFound(_) => StatusCode::NOT_FOUND,
+            GameError::WrongPhase(_) => StatusCode::CONFLICT,
+            GameError::InvalidSettings(_) => StatusCode::BAD_REQUEST,
+            GameError::NotHost => StatusCode::FORBIDDEN,
+            GameError::LobbyUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl GameError {
+    /// Returns whether the error was caused by a frame the client sent
+    pub fn is_malformed_frame(&self) -> bool {
+        matches!(
+            self,
+            GameError::MalformedFrame(_) | GameError::MalformedText | GameError::UnexpectedFrame
+        )
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::{BTreeSet, HashSet};
+
+/// Allocates duck ids that are unique among live ducks
+///
+/// Ids start at 1 since 0 is never issued. Released ids are handed out again
+/// smallest first when recycling, keeping ids small for the wire format
+#[derive(Debug)]
+pub struct IdAllocator {
+    next_id: u32,
+    live_ids: HashSet<u32>,
+    released_ids: BTreeSet<u32>,
+    recycle: bool,
+}
+
+impl IdAllocator {
+    pub fn new(recycle: bool) -> IdAllocator {
+        IdAllocator {
+            next_id: 1,
+            live_ids: HashSet::new(),
+            released_ids: BTreeSet::new(),
+            recycle,
+        }
+    }
+
+    /// Returns a new id not used by any live duck
+    pub fn allocate(&mut self) -> u32 {
+        if let Some(id) = self.released_ids.pop_first() {
+            self.live_ids.insert(id);
+            return id;
+        }
+
+        // skips 0 and ids still live after wrapping around
+        while self.next_id == 0 || self.live_ids.contains(&self.next_id) {
+            self.next_id = self.next_id.wrapping_add(1);
+        }
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.live_ids.insert(id);
+        id
+    }
+
+    /// Frees id of a duck that has left, so it may be recycled
+    pub fn release(&mut self, id: u32) {
+        if self.live_ids.remove(&id) && self.recycle {
+            self.released_ids.insert(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocates_from_one() {
+        let mut ids = IdAllocator::new(true);
+        assert_eq!([ids.allocate(), ids.allocate(), ids.allocate()], [1, 2, 3]);
+    }
+
+    #[test]
+    fn recycles_smallest_released_id_first() {
+        let mut ids = IdAllocator::new(true);
+        (0..4).for_each(|_| {
+            ids.allocate();
+        });
+        ids.release(3);
+        ids.release(2);
+        assert_eq!(ids.allocate(), 2);
+        assert_eq!(ids.allocate(), 3);
+        assert_eq!(ids.allocate(), 5);
+    }
+
+    #[test]
+    fn does_not_recycle_when_disabled() {
+        let mut ids = IdAllocator::new(false);
+        ids.allocate();
+        ids.release(1);
+        assert_eq!(ids.allocate(), 2);
+    }
+
+    #[test]
+    fn ignores_release_of_unknown_ids() {
+        let mut ids = IdAllocator::new(true);
+        ids.allocate();
+        ids.release(7);
+        ids.release(1);
+        ids.release(1);
+        assert_eq!(ids.allocate(), 1);
+        assert_eq!(ids.allocate(), 2);
+    }
+
+    #[test]
+    fn skips_zero_and_live_ids_after_wrapping() {
+        let mut ids = IdAllocator::new(false);
+        assert_eq!(ids.allocate(), 1);
+        ids.next_id = u32::MAX;
+        assert_eq!(ids.allocate(), u32::MAX);
+        assert_eq!(ids.allocate(), 2);
+    }
+}
@@ -4,53 +4,265 @@
 use actix::*;
 use actix_web::{middleware::Logger, web, App, Error, HttpRequest, HttpResponse, HttpServer};
 use actix_web_actors::ws;
-use std::time::Instant;
+use serde::Deserialize;
+use std::{path::PathBuf, time::Instant};
 
 mod actors;
+mod admin;
+mod bread;
+mod collision;
+mod config;
 mod duck;
+mod error;
+mod id_allocator;
 mod messages;
+mod phase;
+mod power_up;
+mod protocol;
 mod protos;
+mod replay;
+mod settings;
+mod snapshot;
+mod vote;
+
+/// Query parameters of the websocket route
+#[derive(Deserialize)]
+struct LobbyQuery {
+    lobby: Option<String>,
+}
 
 /// Spawns a player actor linked to the websocket connection
+///
+/// Player joins the lobby given by `?lobby=CODE`, or the default public lobby
 async fn spawn_player_actor(
     request: HttpRequest,
     stream: web::Payload,
-    server: web::Data<Addr<actors::GameServer>>,
+    query: web::Query<LobbyQuery>,
+    lobby_manager: web::Data<Addr<actors::LobbyManager>>,
+    config: web::Data<config::Config>,
 ) -> Result<HttpResponse, Error> {
+    let lobby_code = match &query.lobby {
+        None => actors::DEFAULT_LOBBY_CODE.to_owned(),
+        Some(code) => match actors::normalize_lobby_code(code) {
+            Some(code) => code,
+            None => return Ok(HttpResponse::BadRequest().body("invalid lobby code")),
+        },
+    };
+
     ws::start(
         actors::Player {
             id: 0,
             last_heartbeat_time: Instant::now(),
-            server_address: server.get_ref().clone(),
+            lobby_code,
+            lobby_manager_address: lobby_manager.get_ref().clone(),
+            server_address: None,
+            config: config.into_inner(),
+            movement_violations: 0,
+            movement_violation_window_start: Instant::now(),
+            protocol: None,
+            malformed_frames: 0,
+            kicked: false,
+            join_pending: false,
+        },
+        &request,
+        stream,
+    )
+}
+
+/// Query parameters of the replay route
+#[derive(Deserialize)]
+struct ReplayQuery {
+    speed: Option<f32>,
+}
+
+/// Most times faster than real time a replay can be played
+const MAX_REPLAY_SPEED: f32 = 16.0;
+
+/// Spawns a replay player actor streaming the recorded round with the given id
+///
+/// Plays at real time, or `?speed=N` times faster
+async fn spawn_replay_actor(
+    request: HttpRequest,
+    stream: web::Payload,
+    id: web::Path<String>,
+    query: web::Query<ReplayQuery>,
+    config: web::Data<config::Config>,
+) -> Result<HttpResponse, Error> {
+    let Some(directory) = config.replay_dir.clone() else {
+        return Ok(HttpResponse::NotFound().body("replays are disabled"));
+    };
+    let id = id.into_inner();
+    if !replay::is_valid_replay_id(&id) {
+        return Ok(HttpResponse::BadRequest().body("invalid replay id"));
+    }
+    let speed = query.speed.unwrap_or(1.0);
+    if !(1.0..=MAX_REPLAY_SPEED).contains(&speed) {
+        return Ok(HttpResponse::BadRequest().body("invalid replay speed"));
+    }
+
+    let events = match web::block(move || replay::read_replay(&directory, &id)).await? {
+        Ok(events) => events,
+        Err(replay::ReadReplayError::NotFound) => {
+            return Ok(HttpResponse::NotFound().body("replay not found"))
+        }
+        Err(e) => return Err(actix_web::error::ErrorInternalServerError(e)),
+    };
+
+    ws::start(
+        actors::ReplayPlayer {
+            events,
+            speed,
+            next_event: 0,
+            playback_start: Instant::now(),
         },
         &request,
         stream,
     )
 }
 
+/// Query parameters of the leaderboard and match history routes
+#[derive(Deserialize)]
+struct LimitQuery {
+    limit: Option<usize>,
+}
+
+impl LimitQuery {
+    /// Returns requested number of entries, 10 by default and at most 100
+    fn limit(&self) -> usize {
+        self.limit.unwrap_or(10).clamp(1, 100)
+    }
+}
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Responds with the highest round scores of all time
+async fn all_time_leaderboard(
+    query: web::Query<LimitQuery>,
+    storage: web::Data<Addr<actors::Storage>>,
+) -> Result<HttpResponse, Error> {
+    leaderboard(0, query.limit(), &storage).await
+}
+
+/// Responds with the highest round scores since midnight UTC
+async fn daily_leaderboard(
+    query: web::Query<LimitQuery>,
+    storage: web::Data<Addr<actors::Storage>>,
+) -> Result<HttpResponse, Error> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(actix_web::error::ErrorInternalServerError)?
+        .as_secs();
+    leaderboard(now - now % SECONDS_PER_DAY, query.limit(), &storage).await
+}
+
+async fn leaderboard(
+    since: u64,
+    limit: usize,
+    storage: &Addr<actors::Storage>,
+) -> Result<HttpResponse, Error> {
+    let entries = storage
+        .send(messages::GetLeaderboard { since, limit })
+        .await
+        .map_err(actix_web::error::ErrorInternalServerError)?
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+    Ok(HttpResponse::Ok().json(entries))
+}
+
+/// Responds with the most recently finished rounds and their ranked participants
+async fn recent_rounds(
+    query: web::Query<LimitQuery>,
+    storage: web::Data<Addr<actors::Storage>>,
+) -> Result<HttpResponse, Error> {
+    let rounds = storage
+        .send(messages::GetRecentRounds {
+            limit: query.limit(),
+        })
+        .await
+        .map_err(actix_web::error::ErrorInternalServerError)?
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+    Ok(HttpResponse::Ok().json(rounds))
+}
+
 /// Starts web server with websocket route /ws for client connection
 ///
-/// Attaches a single game server actor as server state
+/// Attaches a lobby manager actor as server state, which starts a game server per lobby
+///
+/// Settings are loaded from `--config <path>`, environment variables and `.env`,
+/// `--print-config` prints the effective settings and exits
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
 
-    let local_ip = local_ip_address::local_ip();
-    let host = match local_ip.is_ok() {
-        true => local_ip.unwrap().to_string(),
-        false => String::from("localhost"),
+    let mut config_path = None;
+    let mut print_config = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => match args.next() {
+                Some(path) => config_path = Some(PathBuf::from(path)),
+                None => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "--config requires a path",
+                    ))
+                }
+            },
+            "--print-config" => print_config = true,
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("unknown argument: {arg}"),
+                ))
+            }
+        }
+    }
+
+    let config = config::Config::load(config_path)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    if print_config {
+        let text = toml::to_string_pretty(&config).map_err(std::io::Error::other)?;
+        print!("{text}");
+        return Ok(());
+    }
+
+    let host = match &config.host {
+        Some(host) => host.clone(),
+        None => match local_ip_address::local_ip() {
+            Ok(ip) => ip.to_string(),
+            Err(_) => String::from("localhost"),
+        },
     };
-    let port: i32 = 4421;
+    let port = config.port;
+    let workers = config.workers;
+
+    let storage = actors::Storage::open(&config.database_path).map_err(|e| {
+        std::io::Error::other(format!(
+            "failed to open database {}: {e}",
+            config.database_path.display()
+        ))
+    })?;
+    // sqlite calls block, so storage gets its own thread
+    let storage_address =
+        actors::Storage::start_in_arbiter(&Arbiter::new().handle(), move |_| storage);
 
-    let game_actor_address = actors::GameServer::new().start();
+    let config = web::Data::new(config);
+    let lobby_manager_address =
+        actors::LobbyManager::new(storage_address.clone(), config.clone().into_inner()).start();
 
     HttpServer::new(move || {
         App::new()
-            .app_data(web::Data::new(game_actor_address.clone()))
+            .app_data(web::Data::new(lobby_manager_address.clone()))
+            .app_data(web::Data::new(storage_address.clone()))
+            .app_data(config.clone())
             .route("/ws", web::get().to(spawn_player_actor))
+            .route("/replay/{id}", web::get().to(spawn_replay_actor))
+            .route("/leaderboard", web::get().to(all_time_leaderboard))
+            .route("/leaderboard/daily", web::get().to(daily_leaderboard))
+            .route("/rounds", web::get().to(recent_rounds))
+            .configure(admin::configure)
             .wrap(Logger::default())
     })
-    .workers(2)
+    .workers(workers)
     .bind(format!("{host}:{port}"))?
     .run()
     .await
@@ -1,16 +1,54 @@
 //! Messages for actor-actor communication
 
+mod ack_snapshot;
+mod close_lobby;
+mod configure_game;
 mod end_game;
+mod get_lobby;
+mod host;
+mod input;
 mod join_game;
+mod kick;
+mod leaderboard;
 mod leave_game;
+mod list_lobbies;
+mod lobby_status;
+mod phase;
+mod record_round;
+mod resume_game;
 mod start_game;
 mod update;
 mod vote_start_game;
 
+pub use ack_snapshot::AckSnapshot;
+pub use close_lobby::CloseLobby;
+pub use configure_game::CastGameSettings;
+pub use configure_game::ConfigureGame;
+pub use end_game::end_game_cast;
 pub use end_game::CastEndGame;
+pub use end_game::EndGame;
+pub use end_game::EndReason;
+pub use get_lobby::GetLobby;
+pub use host::CastHost;
+pub use input::Input;
+pub use join_game::CastJoinGame;
 pub use join_game::JoinGame;
+pub use join_game::ReJoinGame;
+pub use kick::Kick;
+pub use kick::KickDuck;
+pub use leaderboard::GetLeaderboard;
+pub use leave_game::CastLeaveGame;
 pub use leave_game::LeaveGame;
+pub use list_lobbies::ListLobbies;
+pub use lobby_status::GetLobbyStatus;
+pub use phase::CastPhase;
+pub use record_round::GetRecentRounds;
+pub use record_round::RecordRound;
+pub use resume_game::ResumeGame;
+pub use start_game::CastSpectateGame;
+pub use start_game::CastStartGame;
 pub use start_game::StartGame;
 pub use update::CastUpdateGame;
 pub use update::Update;
+pub use vote_start_game::CastVoteStatus;
 pub use vote_start_game::VoteStartGame;
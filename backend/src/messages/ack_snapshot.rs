@@ -0,0 +1,30 @@
+use actix::prelude::*;
+
+use crate::{actors::GameServer, error::GameError};
+
+/// A message to `GameServer` actor that a duck's client applied a world snapshot
+///
+/// Later updates to the client are sent as deltas from its latest acknowledged snapshot
+#[derive(Message)]
+#[rtype("Result<(), GameError>")]
+pub struct AckSnapshot {
+    pub id: u32,
+    pub snapshot: u32,
+}
+
+impl Handler<AckSnapshot> for GameServer {
+    type Result = Result<(), GameError>;
+
+    fn handle(&mut self, message: AckSnapshot, _: &mut Self::Context) -> Self::Result {
+        if !self.player_actors.contains_key(&message.id) {
+            return Err(GameError::DuckNotFound(message.id));
+        }
+        // acks of snapshots not sent yet, or older than the latest ack, are ignored
+        if message.snapshot > self.snapshots.latest() {
+            return Ok(());
+        }
+        let acked = self.acked_snapshots.entry(message.id).or_default();
+        *acked = message.snapshot.max(*acked);
+        Ok(())
+    }
+}
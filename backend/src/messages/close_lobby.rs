@@ -0,0 +1,24 @@
+use actix::prelude::*;
+
+use crate::actors::{GameServer, LobbyManager};
+
+/// A message to `LobbyManager` actor that a lobby has been empty long enough to close
+///
+/// Gives address of the closing `GameServer` so a newer lobby with same code isn't removed
+#[derive(Message)]
+#[rtype("()")]
+pub struct CloseLobby {
+    pub code: String,
+    pub server_address: Addr<GameServer>,
+}
+
+impl Handler<CloseLobby> for LobbyManager {
+    type Result = ();
+
+    fn handle(&mut self, message: CloseLobby, _: &mut Context<Self>) -> Self::Result {
+        if self.lobbies.get(&message.code) == Some(&message.server_address) {
+            self.lobbies.remove(&message.code);
+            log::info!("CLOSED LOBBY {}", message.code);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use actix::prelude::*;
+
+use crate::{
+    actors::{GameServer, Player},
+    error::GameError,
+    phase::Phase,
+    protos::protos::protos::server_message,
+    settings::GameSettings,
+};
+
+/// A message to `GameServer` actor that a duck wants to change the lobby's settings
+///
+/// Only the lobby's host can change settings, while waiting, within the server's bounds
+#[derive(Message)]
+#[rtype("Result<(), GameError>")]
+pub struct ConfigureGame {
+    pub id: u32,
+    pub settings: GameSettings,
+}
+
+impl Handler<ConfigureGame> for GameServer {
+    type Result = Result<(), GameError>;
+
+    fn handle(&mut self, message: ConfigureGame, _: &mut Context<Self>) -> Self::Result {
+        if !self.player_actors.contains_key(&message.id) {
+            return Err(GameError::DuckNotFound(message.id));
+        }
+        self.check_host(Some(message.id))?;
+        self.check_phase(Phase::Waiting)?;
+        message.settings.validate(&self.config)?;
+        if message.settings == self.settings {
+            return Ok(());
+        }
+
+        log::info!(
+            "DUCK {} CONFIGURED LOBBY {}: {:?}",
+            message.id,
+            self.lobby_code,
+            message.settings
+        );
+        self.settings = message.settings;
+        self.player_actors.iter().for_each(|(_, player)| {
+            player.do_send(CastGameSettings {
+                settings: self.settings.clone(),
+            });
+        });
+        // votes were cast for the previous settings
+        self.reset_votes();
+        Ok(())
+    }
+}
+
+/// A message to `Player` actor to broadcast the lobby's settings
+#[derive(Message)]
+#[rtype("()")]
+pub struct CastGameSettings {
+    pub settings: GameSettings,
+}
+
+impl Handler<CastGameSettings> for Player {
+    type Result = ();
+
+    fn handle(&mut self, message: CastGameSettings, context: &mut Self::Context) -> Self::Result {
+        self.send(
+            server_message::Message::GameSettings(message.settings.to_proto()),
+            context,
+        );
+    }
+}
@@ -1,16 +1,86 @@
 use actix::prelude::*;
+use std::fmt;
 
-use crate::actors::Player;
+use crate::{
+    actors::{GameServer, Player},
+    error::GameError,
+    phase::Phase,
+    protos::protos::protos::{self, server_message},
+};
 
-/// A message to `Player` actor to broadcast game has ended
+/// Why a round ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndReason {
+    /// Round lasted its full duration
+    TimeUp,
+    /// An admin ended the round early
+    Admin,
+    /// No connected player is left in the round
+    PlayersLeft,
+    /// Every bread of the round was eaten
+    AllBreadEaten,
+}
+
+impl fmt::Display for EndReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndReason::TimeUp => write!(f, "time_up"),
+            EndReason::Admin => write!(f, "admin"),
+            EndReason::PlayersLeft => write!(f, "players_left"),
+            EndReason::AllBreadEaten => write!(f, "all_bread_eaten"),
+        }
+    }
+}
+
+/// A message to `GameServer` actor to end the running round now
+///
+/// A round that is still counting down is cancelled instead
+#[derive(Message)]
+#[rtype("Result<(), GameError>")]
+pub struct EndGame {
+    pub reason: EndReason,
+}
+
+impl Handler<EndGame> for GameServer {
+    type Result = Result<(), GameError>;
+
+    fn handle(&mut self, message: EndGame, _: &mut Context<Self>) -> Self::Result {
+        match self.phase {
+            Phase::Countdown => {
+                self.cancel_countdown();
+                Ok(())
+            }
+            Phase::Playing => {
+                self.end_game(message.reason);
+                Ok(())
+            }
+            phase => Err(GameError::WrongPhase(phase)),
+        }
+    }
+}
+
+/// A message to `Player` actor to broadcast game has ended, and why
 #[derive(Message)]
 #[rtype("()")]
-pub struct CastEndGame {}
+pub struct CastEndGame {
+    pub reason: EndReason,
+}
 
 impl Handler<CastEndGame> for Player {
     type Result = ();
 
-    fn handle(&mut self, _: CastEndGame, context: &mut Self::Context) -> Self::Result {
-        context.text("cast:end_game");
+    fn handle(&mut self, message: CastEndGame, context: &mut Self::Context) -> Self::Result {
+        self.send(
+            server_message::Message::EndGame(end_game_cast(message.reason)),
+            context,
+        );
+    }
+}
+
+/// Builds the end of round message sent to players and recorded in replays
+pub fn end_game_cast(reason: EndReason) -> protos::EndGameCast {
+    protos::EndGameCast {
+        reason: reason.to_string(),
+        ..Default::default()
     }
 }
@@ -0,0 +1,23 @@
+use actix::prelude::*;
+
+use crate::actors::{GameServer, LobbyManager};
+
+/// A message to `LobbyManager` actor requesting the game server of a running lobby
+///
+/// Unlike `JoinGame`, doesn't start a lobby that isn't running
+#[derive(Message)]
+#[rtype("Option<Addr<GameServer>>")]
+pub struct GetLobby {
+    pub code: String,
+}
+
+impl Handler<GetLobby> for LobbyManager {
+    type Result = Option<Addr<GameServer>>;
+
+    fn handle(&mut self, message: GetLobby, _: &mut Context<Self>) -> Self::Result {
+        self.lobbies
+            .get(&message.code)
+            .filter(|server| server.connected())
+            .cloned()
+    }
+}
@@ -0,0 +1,29 @@
+use actix::prelude::*;
+
+use crate::{
+    actors::Player,
+    protos::protos::protos::{self, server_message},
+};
+
+/// A message to `Player` actor to broadcast which duck is the lobby's host
+///
+/// Only the host can start rounds, change settings and kick ducks
+#[derive(Message)]
+#[rtype("()")]
+pub struct CastHost {
+    pub id: u32,
+}
+
+impl Handler<CastHost> for Player {
+    type Result = ();
+
+    fn handle(&mut self, message: CastHost, context: &mut Self::Context) -> Self::Result {
+        self.send(
+            server_message::Message::Host(protos::HostCast {
+                id: message.id,
+                ..Default::default()
+            }),
+            context,
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use actix::prelude::*;
+
+use crate::{actors::GameServer, duck::DuckInput, error::GameError};
+
+/// A message to `GameServer` actor with a duck's latest movement intent
+///
+/// Movement is integrated by the server each tick, and the sequence is echoed
+/// back in `UpdateSync` so clients can reconcile their predicted position
+///
+/// Ignored while ducks are frozen for the countdown and podium
+#[derive(Message)]
+#[rtype("Result<(), GameError>")]
+pub struct Input {
+    pub id: u32,
+    pub sequence: u32,
+    pub input: DuckInput,
+}
+
+impl Handler<Input> for GameServer {
+    type Result = Result<(), GameError>;
+
+    fn handle(&mut self, message: Input, _: &mut Self::Context) -> Self::Result {
+        if !self.config.authoritative_movement
+            || self.spectator_ids.contains(&message.id)
+            || self.phase.ducks_frozen()
+        {
+            return Ok(());
+        }
+        let duck = self
+            .ducks
+            .get_mut(&message.id)
+            .ok_or(GameError::DuckNotFound(message.id))?;
+        // drop inputs arriving out of order
+        if message.sequence <= duck.input_sequence {
+            return Ok(());
+        }
+
+        let mut move_x = message.input.move_x;
+        let mut move_z = message.input.move_z;
+        if !move_x.is_finite() || !move_z.is_finite() {
+            (move_x, move_z) = (0.0, 0.0);
+        }
+        // clamp move vector to unit length so ducks can't move faster than full speed
+        let length = f32::hypot(move_x, move_z);
+        if length > 1.0 {
+            move_x /= length;
+            move_z /= length;
+        }
+
+        duck.input = DuckInput {
+            move_x,
+            move_z,
+            jump: duck.input.jump || message.input.jump,
+        };
+        duck.input_sequence = message.sequence;
+        Ok(())
+    }
+}
@@ -1,59 +1,144 @@
 use actix::prelude::*;
-use rand::Rng;
 
 use crate::{
-    actors::{GameServer, Player},
+    actors::{GameServer, LobbyManager, Player},
     duck::Duck,
+    error::GameError,
+    messages::{CastGameSettings, CastPhase, CastSpectateGame},
+    phase::Phase,
+    protos::protos::protos::{self, replay_event, server_message},
 };
 
+/// Most characters in a duck's name, variety or color
+const MAX_DUCK_FIELD_LENGTH: usize = 32;
+
+/// Checks a duck's name, variety or color can be broadcast to other clients
+///
+/// Control characters would break the framing of legacy text messages
+fn validate_duck_field(field: &str, value: &str) -> Result<(), GameError> {
+    if value.chars().count() > MAX_DUCK_FIELD_LENGTH {
+        return Err(GameError::InvalidDuck(format!(
+            "{field} must be at most {MAX_DUCK_FIELD_LENGTH} characters"
+        )));
+    }
+    if value.chars().any(char::is_control) {
+        return Err(GameError::InvalidDuck(format!(
+            "{field} must not contain control characters"
+        )));
+    }
+    Ok(())
+}
+
 /// A message to `GameServer` actor that new player has joined
 ///
-/// Gives address of `Player` actor and name, variety, color of duck
+/// Gives address of `Player` actor, code of the lobby to join, and name, variety, color of duck,
+/// and the team it asks for in team mode
+///
+/// Ducks joining while a round is running spectate until the next round
+///
+/// `LobbyManager` forwards this message to the `GameServer` of the given lobby
 #[derive(Message, Clone)]
-#[rtype("()")]
+#[rtype("Result<(), GameError>")]
 pub struct JoinGame {
     pub player_address: Addr<Player>,
+    pub lobby_code: String,
     pub name: String,
     pub variety: String,
     pub color: String,
+    pub team: Option<u32>,
 }
 
-impl Handler<JoinGame> for GameServer {
-    type Result = ();
+impl Handler<JoinGame> for LobbyManager {
+    type Result = ResponseFuture<Result<(), GameError>>;
+
+    fn handle(&mut self, message: JoinGame, context: &mut Context<Self>) -> Self::Result {
+        let server = match self.get_or_create_lobby(&message.lobby_code, context) {
+            Ok(server) => server,
+            Err(e) => return Box::pin(async move { Err(e) }),
+        };
+        let request = server.send(message);
+        Box::pin(async move { request.await.map_err(|_| GameError::LobbyUnavailable)? })
+    }
+}
 
-    fn handle(&mut self, message: JoinGame, _: &mut Context<Self>) -> Self::Result {
-        // TODO use better id generation
-        let id = self.rng.gen::<u32>();
+impl Handler<JoinGame> for GameServer {
+    type Result = Result<(), GameError>;
 
-        if self.start_time.is_some() {
-            // TODO !!!
+    fn handle(&mut self, message: JoinGame, context: &mut Context<Self>) -> Self::Result {
+        // a player that stopped before joining never sends `LeaveGame`
+        if !message.player_address.connected() {
+            return Err(GameError::PlayerDisconnected);
+        }
+        validate_duck_field("name", &message.name)?;
+        validate_duck_field("variety", &message.variety)?;
+        validate_duck_field("color", &message.color)?;
+
+        let id = self.duck_ids.allocate();
+        let team = self.assign_team(message.team);
+        let host = self.host_id.is_none();
+        if host {
+            log::info!("DUCK {id} IS NOW HOST OF LOBBY {}", self.lobby_code);
+            self.host_id = Some(id);
+        }
 
-            // self.player_actors.insert(id, message.player_address);
-            // self.spectator_ids.insert(id);
-            // return id;
+        // ducks joining during a round spectate until the next one
+        let spectator = self.phase == Phase::Playing;
+        if spectator {
+            self.spectator_ids.insert(id);
         }
 
-        self.player_actors.iter().for_each(|(player_id, player)| {
-            // notify existing actors of new duck
+        // notify existing actors of new duck
+        self.player_actors.iter().for_each(|(_, player)| {
             player.do_send(CastJoinGame {
-                id: id,
+                id,
                 name: message.name.clone(),
                 variety: message.variety.clone(),
                 color: message.color.clone(),
+                spectator,
+                host,
+                team,
             });
-            let duck = self.ducks.get(player_id).unwrap();
-
-            // notify new duck of existing ducks
-            message.player_address.do_send(CastJoinGame {
-                id: *player_id,
-                name: duck.name.clone().unwrap_or_default(),
-                variety: duck.variety.clone().unwrap_or_default(),
-                color: duck.color.clone().unwrap_or_default(),
-            });
         });
 
-        message.player_address.do_send(ReJoinGame { id });
+        self.record(replay_event::Event::JoinGame(protos::JoinGameCast {
+            id,
+            name: message.name.clone(),
+            variety: message.variety.clone(),
+            color: message.color.clone(),
+            spectator,
+            host,
+            team,
+            ..Default::default()
+        }));
+
+        // notify new duck of existing ducks
+        self.send_existing_ducks(id, &message.player_address);
+
+        message.player_address.do_send(ReJoinGame {
+            id,
+            lobby_code: self.lobby_code.clone(),
+            resume_token: self.issue_resume_token(id),
+            server_address: context.address(),
+        });
+
+        message.player_address.do_send(CastGameSettings {
+            settings: self.settings.clone(),
+        });
+        message.player_address.do_send(CastPhase {
+            phase: self.phase,
+            remaining: self.phase_remaining(),
+        });
+
+        if let Some(start_time) = self.start_time {
+            log::info!("DUCK {id} JOINED AS SPECTATOR");
+            message.player_address.do_send(CastSpectateGame {
+                start_time,
+                game_duration: self.settings.game_duration(),
+            });
+        }
 
+        self.empty_since = None;
+        self.bread_resync_ids.insert(id);
         self.player_actors.insert(id, message.player_address);
         self.ducks.insert(
             id,
@@ -61,17 +146,29 @@ impl Handler<JoinGame> for GameServer {
                 name: Some(message.name),
                 variety: Some(message.variety),
                 color: Some(message.color),
+                team,
                 ..Duck::new()
             },
         );
+
+        if self.phase == Phase::Waiting {
+            self.reset_votes();
+        }
+        Ok(())
     }
 }
 
 /// A response message to `Player` actor to communicate the duck's given id
+///
+/// Gives address of the `GameServer` the duck joined for further messages,
+/// and a token the client can use to resume the duck after reconnecting
 #[derive(Message)]
 #[rtype("()")]
 pub struct ReJoinGame {
     pub id: u32,
+    pub lobby_code: String,
+    pub resume_token: String,
+    pub server_address: Addr<GameServer>,
 }
 
 impl Handler<ReJoinGame> for Player {
@@ -79,11 +176,23 @@ impl Handler<ReJoinGame> for Player {
 
     fn handle(&mut self, message: ReJoinGame, context: &mut Self::Context) -> Self::Result {
         self.id = message.id;
-        context.text(vec!["re:join_game", &message.id.to_string()].join("\n"));
+        self.server_address = Some(message.server_address);
+        self.send(
+            server_message::Message::JoinGame(protos::JoinGameReply {
+                id: message.id,
+                lobby_code: message.lobby_code,
+                resume_token: message.resume_token,
+                ..Default::default()
+            }),
+            context,
+        );
     }
 }
 
 /// A message to `Player` actor to broadcast a new duck joining
+///
+/// Spectating ducks are flagged so clients can hide them until the next round,
+/// and the lobby's host is flagged. Gives the duck's team in team mode
 #[derive(Message)]
 #[rtype("()")]
 pub struct CastJoinGame {
@@ -91,21 +200,27 @@ pub struct CastJoinGame {
     pub name: String,
     pub variety: String,
     pub color: String,
+    pub spectator: bool,
+    pub host: bool,
+    pub team: Option<u32>,
 }
 
 impl Handler<CastJoinGame> for Player {
     type Result = ();
 
     fn handle(&mut self, message: CastJoinGame, context: &mut Self::Context) -> Self::Result {
-        context.text(
-            vec![
-                "cast:join_game",
-                &message.id.to_string(),
-                &message.name,
-                &message.variety,
-                &message.color,
-            ]
-            .join("\n"),
+        self.send(
+            server_message::Message::JoinGameCast(protos::JoinGameCast {
+                id: message.id,
+                name: message.name,
+                variety: message.variety,
+                color: message.color,
+                spectator: message.spectator,
+                host: message.host,
+                team: message.team,
+                ..Default::default()
+            }),
+            context,
         );
     }
 }
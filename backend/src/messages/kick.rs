@@ -0,0 +1,52 @@
+use actix::prelude::*;
+
+use crate::{
+    actors::{GameServer, Player},
+    error::GameError,
+};
+
+/// A message to `GameServer` actor to remove a duck from the lobby
+///
+/// The duck's player is disconnected, and the duck can't be resumed
+///
+/// Sent by the lobby's host, or by an admin without a requesting duck
+#[derive(Message)]
+#[rtype("Result<(), GameError>")]
+pub struct KickDuck {
+    pub requester: Option<u32>,
+    pub id: u32,
+    pub reason: String,
+}
+
+impl Handler<KickDuck> for GameServer {
+    type Result = Result<(), GameError>;
+
+    fn handle(&mut self, message: KickDuck, context: &mut Context<Self>) -> Self::Result {
+        self.check_host(message.requester)?;
+        if !self.ducks.contains_key(&message.id) {
+            return Err(GameError::DuckNotFound(message.id));
+        }
+        if let Some(player) = self.player_actors.get(&message.id) {
+            player.do_send(Kick {
+                reason: message.reason,
+            });
+        }
+        self.remove_duck(message.id, context);
+        Ok(())
+    }
+}
+
+/// A message to `Player` actor to notify its client it was kicked, and disconnect it
+#[derive(Message)]
+#[rtype("()")]
+pub struct Kick {
+    pub reason: String,
+}
+
+impl Handler<Kick> for Player {
+    type Result = ();
+
+    fn handle(&mut self, message: Kick, context: &mut Self::Context) -> Self::Result {
+        self.kick(&message.reason, context);
+    }
+}
@@ -0,0 +1,19 @@
+use actix::prelude::*;
+
+use crate::actors::{LeaderboardEntry, Storage};
+
+/// A message to `Storage` actor requesting the highest scores of rounds ended since a unix time
+#[derive(Message)]
+#[rtype("rusqlite::Result<Vec<LeaderboardEntry>>")]
+pub struct GetLeaderboard {
+    pub since: u64,
+    pub limit: usize,
+}
+
+impl Handler<GetLeaderboard> for Storage {
+    type Result = rusqlite::Result<Vec<LeaderboardEntry>>;
+
+    fn handle(&mut self, message: GetLeaderboard, _: &mut Self::Context) -> Self::Result {
+        self.leaderboard(message.since, message.limit)
+    }
+}
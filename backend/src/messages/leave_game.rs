@@ -1,25 +1,35 @@
 use actix::prelude::*;
 
-use crate::actors::{GameServer, Player};
+use crate::{
+    actors::{GameServer, Player},
+    protos::protos::protos::{self, server_message},
+};
 
-/// A message to `GameServer` actor that a duck has left the game
+/// A message to `GameServer` actor that a duck's player has disconnected
+///
+/// The duck is kept for a grace period so it can be resumed, see `ResumeGame`,
+/// unless the player was kicked by the server
+///
+/// Gives address of the disconnecting `Player`, so a player that has already
+/// been replaced by a resumed connection doesn't disconnect the duck
 #[derive(Message)]
 #[rtype("()")]
 pub struct LeaveGame {
     pub id: u32,
+    pub player_address: Addr<Player>,
+    pub kicked: bool,
 }
 
 impl Handler<LeaveGame> for GameServer {
     type Result = ();
 
-    fn handle(&mut self, message: LeaveGame, _: &mut Context<Self>) {
-        log::info!("duck disconnected");
-        if self.player_actors.remove(&message.id).is_some()
-            || self.ducks.remove(&message.id).is_some()
-        {
-            self.player_actors.iter().for_each(|(_, actor)| {
-                actor.do_send(CastLeaveGame { id: message.id });
-            });
+    fn handle(&mut self, message: LeaveGame, context: &mut Context<Self>) {
+        if self.player_actors.get(&message.id) != Some(&message.player_address) {
+            return;
+        }
+        match message.kicked {
+            true => self.remove_duck(message.id, context),
+            false => self.disconnect_duck(message.id, context),
         }
     }
 }
@@ -35,6 +45,12 @@ impl Handler<CastLeaveGame> for Player {
     type Result = ();
 
     fn handle(&mut self, message: CastLeaveGame, context: &mut Self::Context) -> Self::Result {
-        context.text(vec!["cast:leave_game", &message.id.to_string()].join("\n"));
+        self.send(
+            server_message::Message::LeaveGameCast(protos::LeaveGameCast {
+                id: message.id,
+                ..Default::default()
+            }),
+            context,
+        );
     }
 }
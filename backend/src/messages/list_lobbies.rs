@@ -0,0 +1,23 @@
+use actix::prelude::*;
+
+use crate::actors::LobbyManager;
+
+/// A message to `LobbyManager` actor requesting codes of all running lobbies
+#[derive(Message)]
+#[rtype("Vec<String>")]
+pub struct ListLobbies {}
+
+impl Handler<ListLobbies> for LobbyManager {
+    type Result = Vec<String>;
+
+    fn handle(&mut self, _: ListLobbies, _: &mut Context<Self>) -> Self::Result {
+        let mut codes: Vec<String> = self
+            .lobbies
+            .iter()
+            .filter(|(_, server)| server.connected())
+            .map(|(code, _)| code.clone())
+            .collect();
+        codes.sort();
+        codes
+    }
+}
@@ -0,0 +1,69 @@
+use actix::prelude::*;
+use serde::Serialize;
+
+use crate::{actors::GameServer, phase::Phase, settings::GameSettings};
+
+/// A duck in a lobby, as shown to admins
+#[derive(Debug, Serialize)]
+pub struct DuckStatus {
+    pub id: u32,
+    pub name: String,
+    pub variety: String,
+    pub color: String,
+    pub score: u32,
+    pub spectator: bool,
+    pub host: bool,
+    pub team: Option<u32>,
+    pub connected: bool,
+}
+
+/// State of a lobby, as shown to admins
+#[derive(Debug, Serialize)]
+pub struct LobbyStatus {
+    pub code: String,
+    pub phase: Phase,
+    pub settings: GameSettings,
+    pub votes: usize,
+    pub required_votes: usize,
+    pub ducks: Vec<DuckStatus>,
+}
+
+/// A message to `GameServer` actor requesting the state of its lobby
+#[derive(Message)]
+#[rtype("LobbyStatus")]
+pub struct GetLobbyStatus {}
+
+impl Handler<GetLobbyStatus> for GameServer {
+    type Result = MessageResult<GetLobbyStatus>;
+
+    fn handle(&mut self, _: GetLobbyStatus, _: &mut Context<Self>) -> Self::Result {
+        let mut ducks: Vec<DuckStatus> = self
+            .ducks
+            .iter()
+            .map(|(id, duck)| DuckStatus {
+                id: *id,
+                name: duck.name.clone().unwrap_or_default(),
+                variety: duck.variety.clone().unwrap_or_default(),
+                color: duck.color.clone().unwrap_or_default(),
+                score: duck.score,
+                spectator: self.spectator_ids.contains(id),
+                host: self.host_id == Some(*id),
+                team: duck.team,
+                connected: self.player_actors.contains_key(id),
+            })
+            .collect();
+        ducks.sort_by_key(|duck| duck.id);
+
+        MessageResult(LobbyStatus {
+            code: self.lobby_code.clone(),
+            phase: self.phase,
+            settings: self.settings.clone(),
+            votes: self.start_votes.len(),
+            required_votes: self
+                .config
+                .vote_threshold
+                .required_votes(self.player_actors.len()),
+            ducks,
+        })
+    }
+}
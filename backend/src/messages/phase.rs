@@ -0,0 +1,33 @@
+use actix::prelude::*;
+use std::time::Duration;
+
+use crate::{
+    actors::Player,
+    phase::Phase,
+    protos::protos::protos::{self, server_message},
+};
+
+/// A message to `Player` actor to broadcast the lobby moving to a new phase
+///
+/// Gives the time left in the phase, zero for phases without an end
+#[derive(Message)]
+#[rtype("()")]
+pub struct CastPhase {
+    pub phase: Phase,
+    pub remaining: Duration,
+}
+
+impl Handler<CastPhase> for Player {
+    type Result = ();
+
+    fn handle(&mut self, message: CastPhase, context: &mut Self::Context) -> Self::Result {
+        self.send(
+            server_message::Message::Phase(protos::PhaseCast {
+                phase: message.phase.to_proto().into(),
+                remaining_ms: message.remaining.as_millis() as u64,
+                ..Default::default()
+            }),
+            context,
+        );
+    }
+}
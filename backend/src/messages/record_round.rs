@@ -0,0 +1,38 @@
+use actix::prelude::*;
+
+use crate::actors::{RoundRecord, Storage};
+
+/// A message to `Storage` actor to save a finished round to match history
+#[derive(Message)]
+#[rtype("()")]
+pub struct RecordRound {
+    pub round: RoundRecord,
+}
+
+impl Handler<RecordRound> for Storage {
+    type Result = ();
+
+    fn handle(&mut self, message: RecordRound, _: &mut Self::Context) -> Self::Result {
+        if let Err(e) = self.insert_round(&message.round) {
+            log::error!(
+                "Failed to record round in lobby {}: {e}",
+                message.round.lobby_code
+            );
+        }
+    }
+}
+
+/// A message to `Storage` actor requesting the most recently finished rounds
+#[derive(Message)]
+#[rtype("rusqlite::Result<Vec<RoundRecord>>")]
+pub struct GetRecentRounds {
+    pub limit: usize,
+}
+
+impl Handler<GetRecentRounds> for Storage {
+    type Result = rusqlite::Result<Vec<RoundRecord>>;
+
+    fn handle(&mut self, message: GetRecentRounds, _: &mut Self::Context) -> Self::Result {
+        self.recent_rounds(message.limit)
+    }
+}
@@ -0,0 +1,109 @@
+use actix::prelude::*;
+
+use crate::{
+    actors::{GameServer, LobbyManager, Player},
+    error::GameError,
+    messages::{CastGameSettings, CastPhase, CastSpectateGame, CastStartGame, Kick, ReJoinGame},
+    phase::Phase,
+};
+
+/// A message to `GameServer` actor that a reconnected player wants to resume its duck
+///
+/// Gives address of the new `Player` actor, code of the lobby, and the resume
+/// token handed out in `ReJoinGame`
+///
+/// Returns an error if the lobby isn't running or the token isn't valid,
+/// in which case the client should join as a new duck instead
+///
+/// `LobbyManager` forwards this message to the `GameServer` of the given lobby
+#[derive(Message)]
+#[rtype("Result<(), GameError>")]
+pub struct ResumeGame {
+    pub player_address: Addr<Player>,
+    pub lobby_code: String,
+    pub resume_token: String,
+}
+
+impl Handler<ResumeGame> for LobbyManager {
+    type Result = ResponseFuture<Result<(), GameError>>;
+
+    fn handle(&mut self, message: ResumeGame, _: &mut Context<Self>) -> Self::Result {
+        let server = match self.lobbies.get(&message.lobby_code) {
+            Some(server) if server.connected() => server,
+            _ => {
+                let error = GameError::LobbyNotFound(message.lobby_code);
+                return Box::pin(async move { Err(error) });
+            }
+        };
+        let request = server.send(message);
+        Box::pin(async move { request.await.map_err(|_| GameError::LobbyUnavailable)? })
+    }
+}
+
+impl Handler<ResumeGame> for GameServer {
+    type Result = Result<(), GameError>;
+
+    fn handle(&mut self, message: ResumeGame, context: &mut Context<Self>) -> Self::Result {
+        // a player that stopped before resuming never sends `LeaveGame`
+        if !message.player_address.connected() {
+            return Err(GameError::PlayerDisconnected);
+        }
+        let &id = self
+            .resume_tokens
+            .get(&message.resume_token)
+            .ok_or(GameError::InvalidResumeToken)?;
+        let duck = self.ducks.get_mut(&id).ok_or(GameError::DuckNotFound(id))?;
+
+        log::info!("DUCK {id} RESUMED");
+        // duck may have moved while disconnected, so next update can't be speed checked
+        duck.last_update_time = None;
+        // a new connection numbers its inputs from the start again
+        duck.input_sequence = 0;
+        self.disconnected_ducks.remove(&id);
+        self.empty_since = None;
+
+        self.send_existing_ducks(id, &message.player_address);
+
+        message.player_address.do_send(ReJoinGame {
+            id,
+            lobby_code: self.lobby_code.clone(),
+            resume_token: self.issue_resume_token(id),
+            server_address: context.address(),
+        });
+        message.player_address.do_send(CastGameSettings {
+            settings: self.settings.clone(),
+        });
+        message.player_address.do_send(CastPhase {
+            phase: self.phase,
+            remaining: self.phase_remaining(),
+        });
+
+        if let Some(start_time) = self.start_time {
+            let game_duration = self.settings.game_duration();
+            match self.spectator_ids.contains(&id) {
+                true => message.player_address.do_send(CastSpectateGame {
+                    start_time,
+                    game_duration,
+                }),
+                false => message.player_address.do_send(CastStartGame {
+                    start_time,
+                    game_duration,
+                }),
+            }
+        }
+
+        // replaces previous player if its connection hasn't timed out yet
+        self.bread_resync_ids.insert(id);
+        if let Some(previous) = self.player_actors.insert(id, message.player_address) {
+            previous.do_send(Kick {
+                reason: "resumed from another connection".to_owned(),
+            });
+        }
+        self.transfer_host();
+
+        if self.phase == Phase::Waiting {
+            self.reset_votes();
+        }
+        Ok(())
+    }
+}
@@ -1,36 +1,31 @@
 use actix::prelude::*;
 
-use crate::actors::{GameServer, Player};
+use crate::{
+    actors::{GameServer, Player},
+    error::GameError,
+    phase::Phase,
+    protos::protos::protos::{self, server_message},
+};
 
-/// A message to `GameServer` actor to start the game
+/// A message to `GameServer` actor to start the countdown to a round without waiting for votes
 ///
-/// TODO replace this message with vote start
+/// Sent by the lobby's host, or by an admin without a requesting duck.
+/// Uses the given seed for the round's rng if any
 #[derive(Message)]
-#[rtype("()")]
-pub struct StartGame {}
+#[rtype("Result<(), GameError>")]
+pub struct StartGame {
+    pub requester: Option<u32>,
+    pub seed: Option<u64>,
+}
 
 impl Handler<StartGame> for GameServer {
-    type Result = ();
+    type Result = Result<(), GameError>;
 
-    fn handle(&mut self, _: StartGame, _: &mut Self::Context) -> Self::Result {
-        if self.start_time.is_none() {
-            log::info!(
-                "STARTED GAME WITH {} DUCKS WITH DURATION {}",
-                self.ducks.len(),
-                self.game_duration.as_secs()
-            );
-            let start_time = std::time::SystemTime::now();
-            let game_duration = self.game_duration;
-
-            self.player_actors.iter().for_each(|(_, player)| {
-                player.do_send(CastStartGame {
-                    start_time,
-                    game_duration,
-                })
-            });
-
-            self.start_time = Some(start_time);
-        }
+    fn handle(&mut self, message: StartGame, _: &mut Context<Self>) -> Self::Result {
+        self.check_host(message.requester)?;
+        self.check_phase(Phase::Waiting)?;
+        self.start_game(message.seed);
+        Ok(())
     }
 }
 
@@ -38,27 +33,54 @@ impl Handler<StartGame> for GameServer {
 #[derive(Message)]
 #[rtype("()")]
 pub struct CastStartGame {
-    start_time: std::time::SystemTime,
-    game_duration: std::time::Duration,
+    pub start_time: std::time::SystemTime,
+    pub game_duration: std::time::Duration,
 }
 
 impl Handler<CastStartGame> for Player {
     type Result = ();
 
     fn handle(&mut self, message: CastStartGame, context: &mut Self::Context) -> Self::Result {
-        let start_time = message
-            .start_time
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let game_duration = message.game_duration.as_secs();
-        context.text(
-            vec![
-                "cast:start_game",
-                &start_time.to_string(),
-                &game_duration.to_string(),
-            ]
-            .join("\n"),
-        );
+        match start_game_cast(message.start_time, message.game_duration, false) {
+            Ok(cast) => self.send(cast, context),
+            Err(e) => log::error!("{e}"),
+        }
     }
 }
+
+/// A message to `Player` actor that joined during a round, to spectate until it ends
+#[derive(Message)]
+#[rtype("()")]
+pub struct CastSpectateGame {
+    pub start_time: std::time::SystemTime,
+    pub game_duration: std::time::Duration,
+}
+
+impl Handler<CastSpectateGame> for Player {
+    type Result = ();
+
+    fn handle(&mut self, message: CastSpectateGame, context: &mut Self::Context) -> Self::Result {
+        match start_game_cast(message.start_time, message.game_duration, true) {
+            Ok(cast) => self.send(cast, context),
+            Err(e) => log::error!("{e}"),
+        }
+    }
+}
+
+/// Builds the start of round message sent to players and spectators
+fn start_game_cast(
+    start_time: std::time::SystemTime,
+    game_duration: std::time::Duration,
+    spectator: bool,
+) -> Result<server_message::Message, GameError> {
+    let start_time = start_time
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(GameError::Clock)?
+        .as_secs();
+    Ok(server_message::Message::StartGame(protos::StartGameCast {
+        start_time,
+        game_duration: game_duration.as_secs(),
+        spectator,
+        ..Default::default()
+    }))
+}
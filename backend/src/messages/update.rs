@@ -1,25 +1,80 @@
 use actix::prelude::*;
+use std::time::Instant;
 
-use crate::actors::{GameServer, Player};
+const UPDATE_SYNC_FIELD_NUMBER: u32 = 10;
+
+use crate::{
+    actors::{GameServer, Player},
+    duck::MovementViolation,
+    error::GameError,
+    protocol::Protocol,
+};
 
 /// A message to `GameServer` actor with an update of a duck's state
+///
+/// Impossible moves are rejected or clamped, and reported back to the `Player`.
+/// Ducks are kept from swimming into other ducks
+///
+/// Ignored when the server is authoritative over movement, see `Input`, when spectating,
+/// or while ducks are frozen for the countdown and podium
 #[derive(Message)]
-#[rtype("()")]
+#[rtype("Result<(), GameError>")]
 pub struct Update {
     pub id: u32,
     pub duck: crate::duck::Duck,
 }
 
 impl Handler<Update> for GameServer {
-    type Result = ();
+    type Result = Result<(), GameError>;
 
     fn handle(&mut self, msg: Update, _: &mut Self::Context) -> Self::Result {
-        if let Some(duck) = self.ducks.get_mut(&msg.id) {
-            duck.x = msg.duck.x;
-            duck.y = msg.duck.y;
-            duck.z = msg.duck.z;
-            duck.rotation_radians = msg.duck.rotation_radians;
+        if self.config.authoritative_movement
+            || self.spectator_ids.contains(&msg.id)
+            || self.phase.ducks_frozen()
+        {
+            return Ok(());
+        }
+        let duck = self
+            .ducks
+            .get_mut(&msg.id)
+            .ok_or(GameError::DuckNotFound(msg.id))?;
+        if let Some(violation) = duck.apply_update(&msg.duck, Instant::now()) {
+            if let Some(player) = self.player_actors.get(&msg.id) {
+                player.do_send(MovementRejected { violation });
+            }
         }
+        self.keep_clear_of_ducks(msg.id);
+        Ok(())
+    }
+}
+
+/// Wraps serialized UpdateSync bytes in a `ServerMessage` envelope
+///
+/// UpdateSync is already serialized, so its bytes are written as the envelope's
+/// update_sync field directly instead of parsing it back into a message
+fn wrap_update_sync(update_data: &[u8]) -> Result<Vec<u8>, GameError> {
+    let mut envelope = Vec::with_capacity(update_data.len() + 6);
+    let mut stream = protobuf::CodedOutputStream::vec(&mut envelope);
+    stream
+        .write_bytes(UPDATE_SYNC_FIELD_NUMBER, update_data)
+        .and_then(|_| stream.flush())
+        .map_err(GameError::Serialize)?;
+    drop(stream);
+    Ok(envelope)
+}
+
+/// A response message to `Player` actor that its duck's update was rejected or clamped
+#[derive(Message)]
+#[rtype("()")]
+pub struct MovementRejected {
+    pub violation: MovementViolation,
+}
+
+impl Handler<MovementRejected> for Player {
+    type Result = ();
+
+    fn handle(&mut self, message: MovementRejected, context: &mut Self::Context) -> Self::Result {
+        self.record_movement_violation(message.violation, context);
     }
 }
 
@@ -34,6 +89,12 @@ impl Handler<CastUpdateGame> for Player {
     type Result = ();
 
     fn handle(&mut self, message: CastUpdateGame, context: &mut Self::Context) -> Self::Result {
-        context.binary(message.update_data);
+        match self.protocol {
+            Some(Protocol::Structured) => match wrap_update_sync(&message.update_data) {
+                Ok(envelope) => context.binary(envelope),
+                Err(e) => log::error!("{e}"),
+            },
+            _ => context.binary(message.update_data),
+        }
     }
 }
@@ -1,16 +1,74 @@
 use actix::prelude::*;
 
-use crate::actors::GameServer;
+use crate::{
+    actors::{GameServer, Player},
+    error::GameError,
+    phase::Phase,
+    protos::protos::protos::{self, server_message},
+};
 
-/// TODO message not implemented yet
+/// A message to `GameServer` actor that a duck voted to start, or retracted their vote
+///
+/// Starts the countdown once the lobby's vote threshold is reached, only while waiting
 #[derive(Message)]
-#[rtype("()")]
-pub struct VoteStartGame {}
+#[rtype("Result<(), GameError>")]
+pub struct VoteStartGame {
+    pub id: u32,
+    pub vote: bool,
+}
 
 impl Handler<VoteStartGame> for GameServer {
+    type Result = Result<(), GameError>;
+
+    fn handle(&mut self, message: VoteStartGame, _: &mut Self::Context) -> Self::Result {
+        if !self.player_actors.contains_key(&message.id) {
+            return Err(GameError::DuckNotFound(message.id));
+        }
+        self.check_phase(Phase::Waiting)?;
+
+        let changed = match message.vote {
+            true => self.start_votes.insert(message.id),
+            false => self.start_votes.remove(&message.id),
+        };
+        if !changed {
+            return Ok(());
+        }
+
+        self.broadcast_vote_status();
+
+        if self.start_votes.len()
+            >= self
+                .config
+                .vote_threshold
+                .required_votes(self.player_actors.len())
+        {
+            self.start_game(None);
+        }
+        Ok(())
+    }
+}
+
+/// A message to `Player` actor to broadcast how many ducks have voted to start
+#[derive(Message)]
+#[rtype("()")]
+pub struct CastVoteStatus {
+    pub votes: usize,
+    pub ducks: usize,
+    pub required_votes: usize,
+}
+
+impl Handler<CastVoteStatus> for Player {
     type Result = ();
 
-    fn handle(&mut self, message: VoteStartGame, context: &mut Self::Context) -> Self::Result {
-        // TODO !!!
+    fn handle(&mut self, message: CastVoteStatus, context: &mut Self::Context) -> Self::Result {
+        self.send(
+            server_message::Message::VoteStatus(protos::VoteStatusCast {
+                votes: message.votes as u32,
+                ducks: message.ducks as u32,
+                required_votes: message.required_votes as u32,
+                ..Default::default()
+            }),
+            context,
+        );
     }
 }
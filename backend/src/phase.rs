@@ -0,0 +1,49 @@
+use crate::protos::protos::protos;
+use serde::Serialize;
+use std::fmt;
+
+/// Phase of a lobby's round cycle
+///
+/// Lobbies wait for a start, count down, play the round, then show the podium
+/// before waiting again
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    /// Ducks swim freely and vote to start
+    Waiting,
+    /// Round is about to start, ducks are frozen
+    Countdown,
+    /// Round is running
+    Playing,
+    /// Round is over and the top ducks stand on the podium, ducks are frozen
+    Podium,
+}
+
+impl Phase {
+    /// Returns whether ducks can't move in this phase
+    ///
+    /// Updates and inputs sent while frozen are dropped
+    pub fn ducks_frozen(&self) -> bool {
+        matches!(self, Phase::Countdown | Phase::Podium)
+    }
+
+    pub fn to_proto(self) -> protos::Phase {
+        match self {
+            Phase::Waiting => protos::Phase::WAITING,
+            Phase::Countdown => protos::Phase::COUNTDOWN,
+            Phase::Playing => protos::Phase::PLAYING,
+            Phase::Podium => protos::Phase::PODIUM,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Waiting => write!(f, "waiting"),
+            Phase::Countdown => write!(f, "countdown"),
+            Phase::Playing => write!(f, "playing"),
+            Phase::Podium => write!(f, "podium"),
+        }
+    }
+}
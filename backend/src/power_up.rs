@@ -0,0 +1,113 @@
+//! Power-ups floating in the pond during rounds
+//!
+//! A duck swimming into a power-up gets its effect for a while. Picking up a
+//! power-up it already has restarts the effect's timer.
+
+use crate::protos::protos::protos;
+use std::{fmt, time::Duration};
+
+/// Speed multiplier of ducks with a speed boost
+pub const SPEED_BOOST: f32 = 1.6;
+/// Horizontal distance bread is pulled from by a magnet
+pub const MAGNET_RADIUS: f32 = 3.0;
+/// Horizontal distance per second bread is pulled towards a duck with a magnet
+pub const MAGNET_PULL_SPEED: f32 = 4.0;
+
+/// Returns the share of the push apart taken by a duck bumping into another
+///
+/// Ducks with a shield hold their ground, pushing a duck without one the whole way
+pub fn shield_push_share(shielded: bool, other_shielded: bool) -> f32 {
+    match (shielded, other_shielded) {
+        (true, false) => 0.0,
+        (false, true) => 1.0,
+        _ => 0.5,
+    }
+}
+
+/// Effect a power-up gives the duck picking it up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerUpKind {
+    /// Duck swims faster
+    Speed,
+    /// Nearby bread is pulled towards the duck
+    Magnet,
+    /// Bread is worth twice as much
+    DoublePoints,
+    /// Duck holds its ground against other ducks and can't be stolen from
+    Shield,
+}
+
+impl PowerUpKind {
+    /// Every kind, equally likely to spawn
+    pub const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::Speed,
+        PowerUpKind::Magnet,
+        PowerUpKind::DoublePoints,
+        PowerUpKind::Shield,
+    ];
+
+    /// Returns how long the effect lasts after pickup
+    pub fn duration(&self) -> Duration {
+        match self {
+            PowerUpKind::Speed => Duration::from_secs(5),
+            PowerUpKind::Magnet => Duration::from_secs(8),
+            PowerUpKind::DoublePoints => Duration::from_secs(10),
+            PowerUpKind::Shield => Duration::from_secs(10),
+        }
+    }
+
+    pub fn to_proto(self) -> protos::PowerUpKind {
+        match self {
+            PowerUpKind::Speed => protos::PowerUpKind::SPEED,
+            PowerUpKind::Magnet => protos::PowerUpKind::MAGNET,
+            PowerUpKind::DoublePoints => protos::PowerUpKind::DOUBLE_POINTS,
+            PowerUpKind::Shield => protos::PowerUpKind::SHIELD,
+        }
+    }
+}
+
+impl fmt::Display for PowerUpKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerUpKind::Speed => write!(f, "speed"),
+            PowerUpKind::Magnet => write!(f, "magnet"),
+            PowerUpKind::DoublePoints => write!(f, "double points"),
+            PowerUpKind::Shield => write!(f, "shield"),
+        }
+    }
+}
+
+/// A power-up floating in the pond
+#[derive(Debug, Clone)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl PowerUp {
+    pub fn to_proto(&self, id: u32) -> protos::PowerUp {
+        protos::PowerUp {
+            id,
+            kind: self.kind.to_proto().into(),
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shield_holds_ground() {
+        assert_eq!(shield_push_share(true, false), 0.0);
+        assert_eq!(shield_push_share(false, true), 1.0);
+        assert_eq!(shield_push_share(false, false), 0.5);
+        assert_eq!(shield_push_share(true, true), 0.5);
+    }
+}
@@ -0,0 +1,263 @@
+//! Client protocol framing
+//!
+//! Structured clients send and receive a single protobuf `ClientMessage` or
+//! `ServerMessage` envelope per binary frame, starting with a `Hello` handshake.
+//!
+//! Legacy clients use newline separated text frames, with `Duck` or `DuckInput`
+//! protos as binary frames. The legacy protocol will be removed in the next release.
+
+use crate::protos::protos::protos::{self, client_message, server_message};
+
+/// Version of the structured protocol spoken by this server
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Framing used by a connected client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Legacy,
+    Structured,
+}
+
+/// Parses a legacy text frame into a client message
+///
+/// Returns None for unknown messages or messages missing fields
+pub fn parse_legacy_text(text: &str) -> Option<client_message::Message> {
+    let v: Vec<&str> = text.trim().split('\n').collect();
+
+    match v.as_slice() {
+        ["join_game", name, variety, color, rest @ ..] => {
+            Some(client_message::Message::JoinGame(protos::JoinGameRequest {
+                name: name.to_string(),
+                variety: variety.to_string(),
+                color: color.to_string(),
+                team: match rest.first() {
+                    Some(team) => Some(team.parse().ok()?),
+                    None => None,
+                },
+                ..Default::default()
+            }))
+        }
+        ["vote_start_game"] | ["retract_vote_start_game"] => Some(
+            client_message::Message::VoteStartGame(protos::VoteStartGameRequest {
+                vote: v[0] == "vote_start_game",
+                ..Default::default()
+            }),
+        ),
+        ["configure_game", game_duration_secs, bread_spawn_per_second, bread_limit, map, rest @ ..] =>
+        {
+            let bread_weights = match rest {
+                [] => None,
+                [crust, golden_loaf, stale] => Some(protos::BreadWeights {
+                    crust: crust.parse().ok()?,
+                    golden_loaf: golden_loaf.parse().ok()?,
+                    stale: stale.parse().ok()?,
+                    ..Default::default()
+                }),
+                _ => return None,
+            };
+            Some(client_message::Message::ConfigureGame(
+                protos::GameSettings {
+                    game_duration_secs: game_duration_secs.parse().ok()?,
+                    bread_spawn_per_second: bread_spawn_per_second.parse().ok()?,
+                    bread_limit: bread_limit.parse().ok()?,
+                    map: map.to_string(),
+                    bread_weights: bread_weights.into(),
+                    ..Default::default()
+                },
+            ))
+        }
+        ["start_game"] => Some(client_message::Message::StartGame(
+            protos::StartGameRequest::new(),
+        )),
+        ["kick", id] => Some(client_message::Message::Kick(protos::KickRequest {
+            id: id.parse().ok()?,
+            ..Default::default()
+        })),
+        ["resume_game", resume_token] => Some(client_message::Message::ResumeGame(
+            protos::ResumeGameRequest {
+                resume_token: resume_token.to_string(),
+                ..Default::default()
+            },
+        )),
+        _ => None,
+    }
+}
+
+/// Formats a server message as a legacy text frame
+///
+/// Returns None for messages with no legacy equivalent
+pub fn legacy_text(message: &server_message::Message) -> Option<String> {
+    let text = match message {
+        server_message::Message::Hello(_) | server_message::Message::UpdateSync(_) => return None,
+        server_message::Message::JoinGame(reply) => [
+            "re:join_game",
+            &reply.id.to_string(),
+            &reply.lobby_code,
+            &reply.resume_token,
+        ]
+        .join("\n"),
+        server_message::Message::ResumeGameFailed(_) => "re:resume_game_failed".to_owned(),
+        server_message::Message::StartGame(cast) => [
+            match cast.spectator {
+                true => "cast:spectate_game",
+                false => "cast:start_game",
+            },
+            &cast.start_time.to_string(),
+            &cast.game_duration.to_string(),
+        ]
+        .join("\n"),
+        server_message::Message::EndGame(cast) => ["cast:end_game", &cast.reason].join("\n"),
+        server_message::Message::JoinGameCast(cast) => [
+            "cast:join_game",
+            &cast.id.to_string(),
+            &cast.name,
+            &cast.variety,
+            &cast.color,
+            if cast.spectator { "1" } else { "0" },
+            if cast.host { "1" } else { "0" },
+            &cast.team.map(|team| team.to_string()).unwrap_or_default(),
+        ]
+        .join("\n"),
+        server_message::Message::LeaveGameCast(cast) => {
+            ["cast:leave_game", &cast.id.to_string()].join("\n")
+        }
+        server_message::Message::VoteStatus(cast) => [
+            "cast:vote_status",
+            &cast.votes.to_string(),
+            &cast.ducks.to_string(),
+            &cast.required_votes.to_string(),
+        ]
+        .join("\n"),
+        server_message::Message::Phase(cast) => [
+            "cast:phase",
+            match cast.phase.enum_value_or_default() {
+                protos::Phase::WAITING => "waiting",
+                protos::Phase::COUNTDOWN => "countdown",
+                protos::Phase::PLAYING => "playing",
+                protos::Phase::PODIUM => "podium",
+            },
+            &cast.remaining_ms.to_string(),
+        ]
+        .join("\n"),
+        server_message::Message::Host(cast) => ["cast:host", &cast.id.to_string()].join("\n"),
+        server_message::Message::Kick(cast) => ["cast:kick", &cast.reason].join("\n"),
+        server_message::Message::Error(cast) => ["cast:error", &cast.message].join("\n"),
+        server_message::Message::GameSettings(cast) => [
+            "cast:game_settings",
+            &cast.game_duration_secs.to_string(),
+            &cast.bread_spawn_per_second.to_string(),
+            &cast.bread_limit.to_string(),
+            &cast.map,
+            &cast.bread_weights.crust.to_string(),
+            &cast.bread_weights.golden_loaf.to_string(),
+            &cast.bread_weights.stale.to_string(),
+        ]
+        .join("\n"),
+    };
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_join_game_with_and_without_team() {
+        let Some(client_message::Message::JoinGame(join)) =
+            parse_legacy_text("join_game\nDucky\n2\n#ffff00")
+        else {
+            panic!("expected join_game");
+        };
+        assert_eq!(
+            (
+                join.name.as_str(),
+                join.variety.as_str(),
+                join.color.as_str()
+            ),
+            ("Ducky", "2", "#ffff00")
+        );
+        assert_eq!(join.team, None);
+
+        let Some(client_message::Message::JoinGame(join)) =
+            parse_legacy_text("join_game\nDucky\n2\n#ffff00\n1")
+        else {
+            panic!("expected join_game");
+        };
+        assert_eq!(join.team, Some(1));
+    }
+
+    #[test]
+    fn parses_votes() {
+        for (text, vote) in [
+            ("vote_start_game", true),
+            ("retract_vote_start_game", false),
+        ] {
+            let Some(client_message::Message::VoteStartGame(request)) = parse_legacy_text(text)
+            else {
+                panic!("expected vote for {text}");
+            };
+            assert_eq!(request.vote, vote);
+        }
+    }
+
+    #[test]
+    fn parses_configure_game_with_optional_bread_weights() {
+        let Some(client_message::Message::ConfigureGame(settings)) =
+            parse_legacy_text("configure_game\n60\n2.5\n100\npond")
+        else {
+            panic!("expected configure_game");
+        };
+        assert_eq!(settings.game_duration_secs, 60);
+        assert_eq!(settings.bread_spawn_per_second, 2.5);
+        assert_eq!(settings.bread_limit, 100);
+        assert_eq!(settings.map, "pond");
+        assert!(settings.bread_weights.is_none());
+
+        let Some(client_message::Message::ConfigureGame(settings)) =
+            parse_legacy_text("configure_game\n60\n2.5\n100\npond\n4\n2\n1")
+        else {
+            panic!("expected configure_game");
+        };
+        let weights = settings.bread_weights.unwrap();
+        assert_eq!(
+            (weights.crust, weights.golden_loaf, weights.stale),
+            (4, 2, 1)
+        );
+    }
+
+    #[test]
+    fn parses_kick_and_resume() {
+        assert!(matches!(
+            parse_legacy_text("kick\n7"),
+            Some(client_message::Message::Kick(kick)) if kick.id == 7
+        ));
+        assert!(matches!(
+            parse_legacy_text("resume_game\nabc123"),
+            Some(client_message::Message::ResumeGame(resume)) if resume.resume_token == "abc123"
+        ));
+        assert!(matches!(
+            parse_legacy_text("start_game\n"),
+            Some(client_message::Message::StartGame(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_and_incomplete_messages() {
+        for text in [
+            "",
+            "dance",
+            "join_game\nDucky\n2",
+            "join_game\nDucky\n2\n#ffff00\nred",
+            "vote_start_game\nextra",
+            "configure_game\nsixty\n2.5\n100\npond",
+            "configure_game\n60\n2.5\n100\npond\n4\n2",
+            "kick\nduck",
+            "resume_game",
+        ] {
+            assert!(
+                parse_legacy_text(text).is_none(),
+                "{text:?} should be rejected"
+            );
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod protos {
     include!(concat!(env!("OUT_DIR"), "/protos/mod.rs"));
 }
@@ -0,0 +1,109 @@
+//! Round replay files
+//!
+//! A replay is a sequence of length-prefixed `ReplayEvent` protos, recorded from
+//! the start to the end of a round and stored as `<id>.replay` in the replay directory.
+
+use crate::protos::protos::protos::{self, replay_event};
+use protobuf::{CodedInputStream, Message};
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+const REPLAY_EXTENSION: &str = "replay";
+const REPLAY_ID_MAX_LENGTH: usize = 64;
+
+/// Writes a round's events to its replay file
+#[derive(Debug)]
+pub struct ReplayRecorder {
+    pub id: String,
+    writer: BufWriter<File>,
+    started: Instant,
+}
+
+impl ReplayRecorder {
+    /// Creates a new replay file for a round starting now in the given lobby
+    pub fn create(directory: &Path, lobby_code: &str) -> io::Result<ReplayRecorder> {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(io::Error::other)?
+            .as_millis();
+        let id = format!("{lobby_code}-{millis}");
+
+        fs::create_dir_all(directory)?;
+        let file = File::create_new(replay_path(directory, &id))?;
+        Ok(ReplayRecorder {
+            id,
+            writer: BufWriter::new(file),
+            started: Instant::now(),
+        })
+    }
+
+    /// Appends an event, timestamped with the time since the round started
+    pub fn record(&mut self, event: replay_event::Event) -> protobuf::Result<()> {
+        let event = protos::ReplayEvent {
+            time: self.started.elapsed().as_millis() as u64,
+            event: Some(event),
+            ..Default::default()
+        };
+        event.write_length_delimited_to_writer(&mut self.writer)
+    }
+
+    /// Flushes all recorded events to the file
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Returns whether the id could belong to a recorded replay
+///
+/// Ids are a lobby code and a timestamp, so only ascii alphanumerics and dashes are allowed
+pub fn is_valid_replay_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= REPLAY_ID_MAX_LENGTH
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// Reason a replay couldn't be read
+#[derive(Debug)]
+pub enum ReadReplayError {
+    NotFound,
+    Io(io::Error),
+    Malformed(protobuf::Error),
+}
+
+impl fmt::Display for ReadReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadReplayError::NotFound => write!(f, "replay not found"),
+            ReadReplayError::Io(e) => write!(f, "failed to read replay: {e}"),
+            ReadReplayError::Malformed(e) => write!(f, "malformed replay: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadReplayError {}
+
+/// Reads all events of a recorded replay
+pub fn read_replay(
+    directory: &Path,
+    id: &str,
+) -> Result<Vec<protos::ReplayEvent>, ReadReplayError> {
+    let data = fs::read(replay_path(directory, id)).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => ReadReplayError::NotFound,
+        _ => ReadReplayError::Io(e),
+    })?;
+    let mut stream = CodedInputStream::from_bytes(&data);
+    let mut events = Vec::new();
+    while !stream.eof().map_err(ReadReplayError::Malformed)? {
+        events.push(stream.read_message().map_err(ReadReplayError::Malformed)?);
+    }
+    Ok(events)
+}
+
+fn replay_path(directory: &Path, id: &str) -> PathBuf {
+    directory.join(id).with_extension(REPLAY_EXTENSION)
+}
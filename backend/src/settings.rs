@@ -0,0 +1,103 @@
+//! Per-lobby game settings
+//!
+//! Lobbies start with the server's configured settings, and can change them
+//! between rounds within the bounds set in the server config.
+
+use crate::{bread::BreadWeights, config::Config, error::GameError, protos::protos::protos};
+use serde::Serialize;
+use std::time::Duration;
+
+/// Settings a lobby plays its rounds with
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameSettings {
+    pub game_duration_secs: u64,
+    pub bread_spawn_per_second: f32,
+    pub bread_limit: usize,
+    /// Map clients render the pond with
+    pub map: String,
+    pub bread_weights: BreadWeights,
+}
+
+impl GameSettings {
+    /// Returns the server's default settings for new lobbies
+    pub fn new(config: &Config) -> GameSettings {
+        GameSettings {
+            game_duration_secs: config.game_duration_secs,
+            bread_spawn_per_second: config.bread_spawn_per_second,
+            bread_limit: config.bread_limit,
+            // config validation guarantees at least one map
+            map: config.maps[0].clone(),
+            bread_weights: config.bread_weights,
+        }
+    }
+
+    /// Checks settings chosen by a client are within the server's bounds
+    pub fn validate(&self, config: &Config) -> Result<(), GameError> {
+        let check = |valid: bool, message: String| match valid {
+            true => Ok(()),
+            false => Err(GameError::InvalidSettings(message)),
+        };
+
+        check(
+            (1..=config.max_game_duration_secs).contains(&self.game_duration_secs),
+            format!(
+                "game duration must be between 1 and {} seconds",
+                config.max_game_duration_secs
+            ),
+        )?;
+        check(
+            (0.0..=config.max_bread_spawn_per_second).contains(&self.bread_spawn_per_second),
+            format!(
+                "bread spawn rate must be between 0 and {} per second",
+                config.max_bread_spawn_per_second
+            ),
+        )?;
+        check(
+            self.bread_limit <= config.max_bread_limit,
+            format!("bread limit must be at most {}", config.max_bread_limit),
+        )?;
+        // rounds would end as soon as they start without any bread to eat
+        check(
+            !config.end_when_all_bread_eaten || self.bread_limit > 0,
+            "bread limit must be at least 1".to_owned(),
+        )?;
+        check(
+            config.maps.contains(&self.map),
+            format!("map must be one of {}", config.maps.join(", ")),
+        )?;
+        check(
+            self.bread_weights.total() > 0,
+            "bread weights must not all be 0".to_owned(),
+        )?;
+        Ok(())
+    }
+
+    pub fn game_duration(&self) -> Duration {
+        Duration::from_secs(self.game_duration_secs)
+    }
+
+    pub fn to_proto(&self) -> protos::GameSettings {
+        protos::GameSettings {
+            game_duration_secs: self.game_duration_secs,
+            bread_spawn_per_second: self.bread_spawn_per_second,
+            bread_limit: self.bread_limit as u32,
+            map: self.map.clone(),
+            bread_weights: Some(self.bread_weights.to_proto()).into(),
+            ..Default::default()
+        }
+    }
+
+    /// Reads settings sent by a client, using the server's default bread weights if unset
+    pub fn from_proto(settings: protos::GameSettings, config: &Config) -> GameSettings {
+        GameSettings {
+            game_duration_secs: settings.game_duration_secs,
+            bread_spawn_per_second: settings.bread_spawn_per_second,
+            bread_limit: settings.bread_limit as usize,
+            map: settings.map,
+            bread_weights: settings
+                .bread_weights
+                .into_option()
+                .map_or(config.bread_weights, BreadWeights::from),
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Delta compression of `UpdateSync` snapshots
+//!
+//! Every world update is numbered and its quantized duck state kept for a few
+//! seconds. Clients acknowledge snapshots they've applied, and are then sent only
+//! the fields that changed since their latest acknowledged snapshot.
+
+use crate::{duck::Duck, protos::protos::protos};
+use std::{
+    collections::{HashMap, VecDeque},
+    f32::consts::TAU,
+};
+
+/// Quantization steps per unit of position
+pub const POSITION_SCALE: f32 = 100.0;
+/// Quantization steps per full turn of rotation
+pub const ROTATION_STEPS: f32 = 65536.0;
+/// Number of past snapshots usable as a baseline
+///
+/// Clients whose latest acknowledgement is older are sent full snapshots
+const SNAPSHOT_HISTORY: usize = 64;
+
+/// Duck state as sent in delta snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuantizedDuck {
+    pub rotation: u32,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub score: u32,
+    pub input_sequence: u32,
+}
+
+impl QuantizedDuck {
+    pub fn new(duck: &Duck) -> QuantizedDuck {
+        let turns = duck.rotation_radians.rem_euclid(TAU) / TAU;
+        QuantizedDuck {
+            rotation: (turns * ROTATION_STEPS).round() as u32 % ROTATION_STEPS as u32,
+            x: (duck.x * POSITION_SCALE).round() as i32,
+            y: (duck.y * POSITION_SCALE).round() as i32,
+            z: (duck.z * POSITION_SCALE).round() as i32,
+            score: duck.score,
+            input_sequence: duck.input_sequence,
+        }
+    }
+
+    /// Returns fields changed since the baseline, or all fields if the duck is new
+    ///
+    /// Returns None if nothing changed
+    fn delta(&self, id: u32, baseline: Option<&QuantizedDuck>) -> Option<protos::DuckDelta> {
+        if baseline == Some(self) {
+            return None;
+        }
+        fn changed<T: PartialEq + Copy>(value: T, baseline: Option<T>) -> Option<T> {
+            (baseline != Some(value)).then_some(value)
+        }
+        Some(protos::DuckDelta {
+            id,
+            rotation: changed(self.rotation, baseline.map(|duck| duck.rotation)),
+            x: changed(self.x, baseline.map(|duck| duck.x)),
+            y: changed(self.y, baseline.map(|duck| duck.y)),
+            z: changed(self.z, baseline.map(|duck| duck.z)),
+            score: changed(self.score, baseline.map(|duck| duck.score)),
+            input_sequence: changed(
+                self.input_sequence,
+                baseline.map(|duck| duck.input_sequence),
+            ),
+            ..Default::default()
+        })
+    }
+}
+
+/// Recently sent snapshots, oldest first
+#[derive(Debug, Default)]
+pub struct SnapshotHistory {
+    snapshots: VecDeque<(u32, HashMap<u32, QuantizedDuck>)>,
+    latest: u32,
+}
+
+impl SnapshotHistory {
+    /// Records state of the next snapshot, forgetting the oldest past the history length
+    ///
+    /// Returns the new snapshot's number, starting at 1
+    pub fn push(&mut self, ducks: HashMap<u32, QuantizedDuck>) -> u32 {
+        // 0 means no baseline, so it's skipped when wrapping
+        self.latest = self.latest.checked_add(1).unwrap_or(1);
+        self.snapshots.push_back((self.latest, ducks));
+        if self.snapshots.len() > SNAPSHOT_HISTORY {
+            self.snapshots.pop_front();
+        }
+        self.latest
+    }
+
+    /// Returns number of the latest snapshot, or 0 if none were taken
+    pub fn latest(&self) -> u32 {
+        self.latest
+    }
+
+    /// Returns whether the snapshot is still kept and can be used as a baseline
+    pub fn contains(&self, snapshot: u32) -> bool {
+        self.get(snapshot).is_some()
+    }
+
+    fn get(&self, snapshot: u32) -> Option<&HashMap<u32, QuantizedDuck>> {
+        self.snapshots
+            .iter()
+            .find(|(number, _)| *number == snapshot)
+            .map(|(_, ducks)| ducks)
+    }
+
+    /// Fills the delta fields of an UpdateSync with changes from the baseline to the latest snapshot
+    ///
+    /// Returns false without changing the message if the baseline is no longer kept
+    pub fn write_delta(&self, baseline: u32, message: &mut protos::UpdateSync) -> bool {
+        let (Some(from), Some((_, to))) = (self.get(baseline), self.snapshots.back()) else {
+            return false;
+        };
+
+        message.baseline = baseline;
+        message.duck_deltas = to
+            .iter()
+            .filter_map(|(id, duck)| duck.delta(*id, from.get(id)))
+            .collect();
+        message.removed_duck_ids = from
+            .keys()
+            .filter(|id| !to.contains_key(id))
+            .copied()
+            .collect();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn duck(x: f32, z: f32, score: u32) -> QuantizedDuck {
+        QuantizedDuck::new(&Duck {
+            x,
+            z,
+            score,
+            ..Duck::new()
+        })
+    }
+
+    /// Applies a delta message to the baseline's ducks, as a client would
+    fn apply(
+        baseline: &HashMap<u32, QuantizedDuck>,
+        message: &protos::UpdateSync,
+    ) -> HashMap<u32, QuantizedDuck> {
+        let mut ducks = baseline.clone();
+        for id in &message.removed_duck_ids {
+            ducks.remove(id);
+        }
+        for delta in &message.duck_deltas {
+            let duck = ducks.entry(delta.id).or_insert(QuantizedDuck {
+                rotation: 0,
+                x: 0,
+                y: 0,
+                z: 0,
+                score: 0,
+                input_sequence: 0,
+            });
+            duck.rotation = delta.rotation.unwrap_or(duck.rotation);
+            duck.x = delta.x.unwrap_or(duck.x);
+            duck.y = delta.y.unwrap_or(duck.y);
+            duck.z = delta.z.unwrap_or(duck.z);
+            duck.score = delta.score.unwrap_or(duck.score);
+            duck.input_sequence = delta.input_sequence.unwrap_or(duck.input_sequence);
+        }
+        ducks
+    }
+
+    #[test]
+    fn quantizes_position_and_rotation() {
+        let quantized = QuantizedDuck::new(&Duck {
+            x: 1.234,
+            y: -0.5,
+            z: 11.5,
+            rotation_radians: -PI / 2.0,
+            ..Duck::new()
+        });
+        assert_eq!((quantized.x, quantized.y, quantized.z), (123, -50, 1150));
+        assert_eq!(quantized.rotation, 49152);
+
+        let full_turn = QuantizedDuck::new(&Duck {
+            rotation_radians: TAU,
+            ..Duck::new()
+        });
+        assert_eq!(full_turn.rotation, 0);
+    }
+
+    #[test]
+    fn delta_round_trips_to_latest_snapshot() {
+        let mut history = SnapshotHistory::default();
+        let first = HashMap::from([(1, duck(0.0, 0.0, 0)), (2, duck(1.0, 1.0, 3))]);
+        let baseline = history.push(first.clone());
+        let latest = HashMap::from([(1, duck(0.5, 0.0, 1)), (3, duck(-2.0, 4.0, 0))]);
+        history.push(latest.clone());
+
+        let mut message = protos::UpdateSync::new();
+        assert!(history.write_delta(baseline, &mut message));
+        assert_eq!(message.baseline, baseline);
+        assert_eq!(message.removed_duck_ids, vec![2]);
+        assert_eq!(apply(&first, &message), latest);
+    }
+
+    #[test]
+    fn delta_only_carries_changed_fields() {
+        let mut history = SnapshotHistory::default();
+        let baseline = history.push(HashMap::from([
+            (1, duck(0.0, 0.0, 0)),
+            (2, duck(1.0, 1.0, 0)),
+        ]));
+        history.push(HashMap::from([
+            (1, duck(0.0, 0.0, 0)),
+            (2, duck(1.5, 1.0, 0)),
+        ]));
+
+        let mut message = protos::UpdateSync::new();
+        assert!(history.write_delta(baseline, &mut message));
+        assert_eq!(message.duck_deltas.len(), 1);
+        let delta = &message.duck_deltas[0];
+        assert_eq!(delta.id, 2);
+        assert_eq!(delta.x, Some(150));
+        assert_eq!((delta.z, delta.score, delta.rotation), (None, None, None));
+    }
+
+    #[test]
+    fn new_duck_is_sent_in_full() {
+        let mut history = SnapshotHistory::default();
+        let baseline = history.push(HashMap::new());
+        history.push(HashMap::from([(1, duck(0.0, 0.0, 0))]));
+
+        let mut message = protos::UpdateSync::new();
+        assert!(history.write_delta(baseline, &mut message));
+        let delta = &message.duck_deltas[0];
+        assert_eq!(
+            (delta.rotation, delta.x, delta.y, delta.z, delta.score),
+            (Some(0), Some(0), Some(0), Some(0), Some(0))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_full_snapshot_when_baseline_is_gone() {
+        let mut history = SnapshotHistory::default();
+        let oldest = history.push(HashMap::from([(1, duck(0.0, 0.0, 0))]));
+        for _ in 0..SNAPSHOT_HISTORY {
+            history.push(HashMap::from([(1, duck(1.0, 0.0, 0))]));
+        }
+        assert!(!history.contains(oldest));
+        assert!(history.contains(oldest + 1));
+
+        // clients that never acknowledged have baseline 0
+        assert!(!history.contains(0));
+
+        let mut message = protos::UpdateSync::new();
+        assert!(!history.write_delta(oldest, &mut message));
+        assert!(!history.write_delta(0, &mut message));
+        assert_eq!(message, protos::UpdateSync::new());
+    }
+
+    #[test]
+    fn snapshot_numbers_skip_zero_when_wrapping() {
+        let mut history = SnapshotHistory {
+            latest: u32::MAX - 1,
+            ..Default::default()
+        };
+        assert_eq!(history.push(HashMap::new()), u32::MAX);
+        assert_eq!(history.push(HashMap::new()), 1);
+        assert_eq!(history.latest(), 1);
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Number of votes needed before a lobby starts its round
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum VoteThreshold {
+    /// More than half of the ducks in the lobby
+    Majority,
+    /// Every duck in the lobby
+    All,
+    /// A fixed number of ducks, capped at the number of ducks in the lobby
+    Count(usize),
+}
+
+impl VoteThreshold {
+    /// Returns number of votes required to start with given number of ducks
+    ///
+    /// Always requires at least one vote
+    pub fn required_votes(&self, duck_count: usize) -> usize {
+        let required = match self {
+            VoteThreshold::Majority => duck_count / 2 + 1,
+            VoteThreshold::All => duck_count,
+            VoteThreshold::Count(count) => usize::min(*count, duck_count),
+        };
+        required.max(1)
+    }
+}
+
+impl FromStr for VoteThreshold {
+    type Err = String;
+
+    /// Parses "majority", "all", or a positive number of votes
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "majority" => Ok(VoteThreshold::Majority),
+            "all" => Ok(VoteThreshold::All),
+            count => match count.parse::<usize>() {
+                Ok(count) if count > 0 => Ok(VoteThreshold::Count(count)),
+                _ => Err(format!("invalid vote threshold: {s}")),
+            },
+        }
+    }
+}
+
+impl fmt::Display for VoteThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoteThreshold::Majority => write!(f, "majority"),
+            VoteThreshold::All => write!(f, "all"),
+            VoteThreshold::Count(count) => write!(f, "{count}"),
+        }
+    }
+}
+
+impl TryFrom<String> for VoteThreshold {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<VoteThreshold> for String {
+    fn from(threshold: VoteThreshold) -> Self {
+        threshold.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn majority_needs_more_than_half() {
+        let threshold = VoteThreshold::Majority;
+        assert_eq!(threshold.required_votes(1), 1);
+        assert_eq!(threshold.required_votes(2), 2);
+        assert_eq!(threshold.required_votes(3), 2);
+        assert_eq!(threshold.required_votes(4), 3);
+    }
+
+    #[test]
+    fn all_needs_every_duck() {
+        assert_eq!(VoteThreshold::All.required_votes(5), 5);
+    }
+
+    #[test]
+    fn count_is_capped_at_duck_count() {
+        let threshold = VoteThreshold::Count(3);
+        assert_eq!(threshold.required_votes(5), 3);
+        assert_eq!(threshold.required_votes(2), 2);
+    }
+
+    #[test]
+    fn always_needs_a_vote() {
+        for threshold in [
+            VoteThreshold::Majority,
+            VoteThreshold::All,
+            VoteThreshold::Count(1),
+        ] {
+            assert_eq!(threshold.required_votes(0), 1);
+        }
+    }
+
+    #[test]
+    fn parses_thresholds() {
+        assert_eq!("majority".parse(), Ok(VoteThreshold::Majority));
+        assert_eq!(" ALL ".parse(), Ok(VoteThreshold::All));
+        assert_eq!("4".parse(), Ok(VoteThreshold::Count(4)));
+        for text in ["0", "-1", "most", ""] {
+            assert!(
+                text.parse::<VoteThreshold>().is_err(),
+                "{text:?} should fail"
+            );
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for threshold in [
+            VoteThreshold::Majority,
+            VoteThreshold::All,
+            VoteThreshold::Count(2),
+        ] {
+            assert_eq!(threshold.to_string().parse(), Ok(threshold));
+        }
+    }
+}
@@ -23,8 +23,46 @@ var global =
   }.call(null) ||
   Function("return this")();
 
+goog.exportSymbol("proto.Bread", null, global);
+goog.exportSymbol("proto.BreadKind", null, global);
+goog.exportSymbol("proto.BreadPickup", null, global);
+goog.exportSymbol("proto.BreadWeights", null, global);
+goog.exportSymbol("proto.ClientMessage", null, global);
+goog.exportSymbol("proto.ClientMessage.MessageCase", null, global);
 goog.exportSymbol("proto.Duck", null, global);
+goog.exportSymbol("proto.DuckCollision", null, global);
+goog.exportSymbol("proto.DuckDelta", null, global);
+goog.exportSymbol("proto.DuckEffect", null, global);
+goog.exportSymbol("proto.DuckInput", null, global);
+goog.exportSymbol("proto.EndGameCast", null, global);
+goog.exportSymbol("proto.ErrorCast", null, global);
+goog.exportSymbol("proto.GameSettings", null, global);
+goog.exportSymbol("proto.Hello", null, global);
+goog.exportSymbol("proto.HostCast", null, global);
+goog.exportSymbol("proto.JoinGameCast", null, global);
+goog.exportSymbol("proto.JoinGameReply", null, global);
+goog.exportSymbol("proto.JoinGameRequest", null, global);
+goog.exportSymbol("proto.KickCast", null, global);
+goog.exportSymbol("proto.KickRequest", null, global);
+goog.exportSymbol("proto.LeaveGameCast", null, global);
+goog.exportSymbol("proto.Phase", null, global);
+goog.exportSymbol("proto.PhaseCast", null, global);
+goog.exportSymbol("proto.PowerUp", null, global);
+goog.exportSymbol("proto.PowerUpKind", null, global);
+goog.exportSymbol("proto.ReplayEvent", null, global);
+goog.exportSymbol("proto.ReplayEvent.EventCase", null, global);
+goog.exportSymbol("proto.ResumeGameFailed", null, global);
+goog.exportSymbol("proto.ResumeGameRequest", null, global);
+goog.exportSymbol("proto.ServerMessage", null, global);
+goog.exportSymbol("proto.ServerMessage.MessageCase", null, global);
+goog.exportSymbol("proto.SlowedDuck", null, global);
+goog.exportSymbol("proto.SnapshotAck", null, global);
+goog.exportSymbol("proto.StartGameCast", null, global);
+goog.exportSymbol("proto.StartGameRequest", null, global);
+goog.exportSymbol("proto.TeamScore", null, global);
 goog.exportSymbol("proto.UpdateSync", null, global);
+goog.exportSymbol("proto.VoteStartGameRequest", null, global);
+goog.exportSymbol("proto.VoteStatusCast", null, global);
 /**
  * Generated by JsPbCodeGenerator.
  * @param {Array=} opt_data Optional initial data array, typically from a
@@ -46,6 +84,216 @@ if (goog.DEBUG && !COMPILED) {
    */
   proto.Duck.displayName = "proto.Duck";
 }
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.DuckInput = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.DuckInput, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.DuckInput.displayName = "proto.DuckInput";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.Bread = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.Bread, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.Bread.displayName = "proto.Bread";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.BreadWeights = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.BreadWeights, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.BreadWeights.displayName = "proto.BreadWeights";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.PowerUp = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.PowerUp, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.PowerUp.displayName = "proto.PowerUp";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.DuckEffect = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.DuckEffect, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.DuckEffect.displayName = "proto.DuckEffect";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.DuckCollision = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.DuckCollision, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.DuckCollision.displayName = "proto.DuckCollision";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.SlowedDuck = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.SlowedDuck, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.SlowedDuck.displayName = "proto.SlowedDuck";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.TeamScore = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.TeamScore, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.TeamScore.displayName = "proto.TeamScore";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.BreadPickup = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.BreadPickup, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.BreadPickup.displayName = "proto.BreadPickup";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.DuckDelta = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.DuckDelta, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.DuckDelta.displayName = "proto.DuckDelta";
+}
 /**
  * Generated by JsPbCodeGenerator.
  * @param {Array=} opt_data Optional initial data array, typically from a
@@ -59,286 +307,8244 @@ if (goog.DEBUG && !COMPILED) {
 proto.UpdateSync = function (opt_data) {
   Message.initialize(
     this,
-    opt_data,
-    0,
-    -1,
-    proto.UpdateSync.repeatedFields_,
-    null,
+    opt_data,
+    0,
+    -1,
+    proto.UpdateSync.repeatedFields_,
+    null,
+  );
+};
+goog.inherits(proto.UpdateSync, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.UpdateSync.displayName = "proto.UpdateSync";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.Hello = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.Hello, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.Hello.displayName = "proto.Hello";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.JoinGameRequest = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.JoinGameRequest, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.JoinGameRequest.displayName = "proto.JoinGameRequest";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.VoteStartGameRequest = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.VoteStartGameRequest, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.VoteStartGameRequest.displayName = "proto.VoteStartGameRequest";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.ResumeGameRequest = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.ResumeGameRequest, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.ResumeGameRequest.displayName = "proto.ResumeGameRequest";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.StartGameRequest = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.StartGameRequest, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.StartGameRequest.displayName = "proto.StartGameRequest";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.KickRequest = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.KickRequest, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.KickRequest.displayName = "proto.KickRequest";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.SnapshotAck = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.SnapshotAck, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.SnapshotAck.displayName = "proto.SnapshotAck";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.GameSettings = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.GameSettings, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.GameSettings.displayName = "proto.GameSettings";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.ClientMessage = function (opt_data) {
+  Message.initialize(
+    this,
+    opt_data,
+    0,
+    -1,
+    null,
+    proto.ClientMessage.oneofGroups_,
+  );
+};
+goog.inherits(proto.ClientMessage, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.ClientMessage.displayName = "proto.ClientMessage";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.JoinGameReply = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.JoinGameReply, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.JoinGameReply.displayName = "proto.JoinGameReply";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.ResumeGameFailed = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.ResumeGameFailed, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.ResumeGameFailed.displayName = "proto.ResumeGameFailed";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.StartGameCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.StartGameCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.StartGameCast.displayName = "proto.StartGameCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.EndGameCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.EndGameCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.EndGameCast.displayName = "proto.EndGameCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.JoinGameCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.JoinGameCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.JoinGameCast.displayName = "proto.JoinGameCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.LeaveGameCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.LeaveGameCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.LeaveGameCast.displayName = "proto.LeaveGameCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.VoteStatusCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.VoteStatusCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.VoteStatusCast.displayName = "proto.VoteStatusCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.HostCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.HostCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.HostCast.displayName = "proto.HostCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.PhaseCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.PhaseCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.PhaseCast.displayName = "proto.PhaseCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.KickCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.KickCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.KickCast.displayName = "proto.KickCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.ErrorCast = function (opt_data) {
+  Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.ErrorCast, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.ErrorCast.displayName = "proto.ErrorCast";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.ServerMessage = function (opt_data) {
+  Message.initialize(
+    this,
+    opt_data,
+    0,
+    -1,
+    null,
+    proto.ServerMessage.oneofGroups_,
+  );
+};
+goog.inherits(proto.ServerMessage, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.ServerMessage.displayName = "proto.ServerMessage";
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.ReplayEvent = function (opt_data) {
+  Message.initialize(
+    this,
+    opt_data,
+    0,
+    -1,
+    null,
+    proto.ReplayEvent.oneofGroups_,
+  );
+};
+goog.inherits(proto.ReplayEvent, Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.ReplayEvent.displayName = "proto.ReplayEvent";
+}
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.Duck.prototype.toObject = function (opt_includeInstance) {
+    return proto.Duck.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.Duck} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.Duck.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+        rotation: Message.getFloatingPointFieldWithDefault(msg, 2, 0.0),
+        x: Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
+        y: Message.getFloatingPointFieldWithDefault(msg, 4, 0.0),
+        z: Message.getFloatingPointFieldWithDefault(msg, 5, 0.0),
+        score: Message.getFieldWithDefault(msg, 6, 0),
+        inputSequence: Message.getFieldWithDefault(msg, 7, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.Duck}
+ */
+proto.Duck.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.Duck();
+  return proto.Duck.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.Duck} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.Duck}
+ */
+proto.Duck.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setRotation(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setX(value);
+        break;
+      case 4:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setY(value);
+        break;
+      case 5:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setZ(value);
+        break;
+      case 6:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setScore(value);
+        break;
+      case 7:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setInputSequence(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.Duck.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.Duck.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.Duck} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.Duck.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getRotation();
+  if (f !== 0.0) {
+    writer.writeFloat(2, f);
+  }
+  f = message.getX();
+  if (f !== 0.0) {
+    writer.writeFloat(3, f);
+  }
+  f = message.getY();
+  if (f !== 0.0) {
+    writer.writeFloat(4, f);
+  }
+  f = message.getZ();
+  if (f !== 0.0) {
+    writer.writeFloat(5, f);
+  }
+  f = message.getScore();
+  if (f !== 0) {
+    writer.writeUint32(6, f);
+  }
+  f = message.getInputSequence();
+  if (f !== 0) {
+    writer.writeUint32(7, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.Duck.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Duck} returns this
+ */
+proto.Duck.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional float rotation = 2;
+ * @return {number}
+ */
+proto.Duck.prototype.getRotation = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    2,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Duck} returns this
+ */
+proto.Duck.prototype.setRotation = function (value) {
+  return Message.setProto3FloatField(this, 2, value);
+};
+
+/**
+ * optional float x = 3;
+ * @return {number}
+ */
+proto.Duck.prototype.getX = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    3,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Duck} returns this
+ */
+proto.Duck.prototype.setX = function (value) {
+  return Message.setProto3FloatField(this, 3, value);
+};
+
+/**
+ * optional float y = 4;
+ * @return {number}
+ */
+proto.Duck.prototype.getY = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    4,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Duck} returns this
+ */
+proto.Duck.prototype.setY = function (value) {
+  return Message.setProto3FloatField(this, 4, value);
+};
+
+/**
+ * optional float z = 5;
+ * @return {number}
+ */
+proto.Duck.prototype.getZ = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    5,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Duck} returns this
+ */
+proto.Duck.prototype.setZ = function (value) {
+  return Message.setProto3FloatField(this, 5, value);
+};
+
+/**
+ * optional uint32 score = 6;
+ * @return {number}
+ */
+proto.Duck.prototype.getScore = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 6, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Duck} returns this
+ */
+proto.Duck.prototype.setScore = function (value) {
+  return Message.setProto3IntField(this, 6, value);
+};
+
+/**
+ * optional uint32 input_sequence = 7;
+ * @return {number}
+ */
+proto.Duck.prototype.getInputSequence = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 7, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Duck} returns this
+ */
+proto.Duck.prototype.setInputSequence = function (value) {
+  return Message.setProto3IntField(this, 7, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.DuckInput.prototype.toObject = function (opt_includeInstance) {
+    return proto.DuckInput.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.DuckInput} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.DuckInput.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        sequence: Message.getFieldWithDefault(msg, 1, 0),
+        moveX: Message.getFloatingPointFieldWithDefault(msg, 2, 0.0),
+        moveZ: Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
+        jump: Message.getBooleanFieldWithDefault(msg, 4, false),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.DuckInput}
+ */
+proto.DuckInput.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.DuckInput();
+  return proto.DuckInput.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.DuckInput} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.DuckInput}
+ */
+proto.DuckInput.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setSequence(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setMoveX(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setMoveZ(value);
+        break;
+      case 4:
+        var value = /** @type {boolean} */ reader.readBool();
+        msg.setJump(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.DuckInput.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.DuckInput.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.DuckInput} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.DuckInput.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getSequence();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getMoveX();
+  if (f !== 0.0) {
+    writer.writeFloat(2, f);
+  }
+  f = message.getMoveZ();
+  if (f !== 0.0) {
+    writer.writeFloat(3, f);
+  }
+  f = message.getJump();
+  if (f) {
+    writer.writeBool(4, f);
+  }
+};
+
+/**
+ * optional uint32 sequence = 1;
+ * @return {number}
+ */
+proto.DuckInput.prototype.getSequence = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckInput} returns this
+ */
+proto.DuckInput.prototype.setSequence = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional float move_x = 2;
+ * @return {number}
+ */
+proto.DuckInput.prototype.getMoveX = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    2,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckInput} returns this
+ */
+proto.DuckInput.prototype.setMoveX = function (value) {
+  return Message.setProto3FloatField(this, 2, value);
+};
+
+/**
+ * optional float move_z = 3;
+ * @return {number}
+ */
+proto.DuckInput.prototype.getMoveZ = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    3,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckInput} returns this
+ */
+proto.DuckInput.prototype.setMoveZ = function (value) {
+  return Message.setProto3FloatField(this, 3, value);
+};
+
+/**
+ * optional bool jump = 4;
+ * @return {boolean}
+ */
+proto.DuckInput.prototype.getJump = function () {
+  return /** @type {boolean} */ Message.getBooleanFieldWithDefault(
+    this,
+    4,
+    false,
+  );
+};
+
+/**
+ * @param {boolean} value
+ * @return {!proto.DuckInput} returns this
+ */
+proto.DuckInput.prototype.setJump = function (value) {
+  return Message.setProto3BooleanField(this, 4, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.Bread.prototype.toObject = function (opt_includeInstance) {
+    return proto.Bread.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.Bread} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.Bread.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+        x: Message.getFloatingPointFieldWithDefault(msg, 2, 0.0),
+        y: Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
+        z: Message.getFloatingPointFieldWithDefault(msg, 4, 0.0),
+        kind: Message.getFieldWithDefault(msg, 5, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.Bread}
+ */
+proto.Bread.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.Bread();
+  return proto.Bread.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.Bread} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.Bread}
+ */
+proto.Bread.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setX(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setY(value);
+        break;
+      case 4:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setZ(value);
+        break;
+      case 5:
+        var value = /** @type {!proto.BreadKind} */ reader.readEnum();
+        msg.setKind(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.Bread.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.Bread.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.Bread} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.Bread.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getX();
+  if (f !== 0.0) {
+    writer.writeFloat(2, f);
+  }
+  f = message.getY();
+  if (f !== 0.0) {
+    writer.writeFloat(3, f);
+  }
+  f = message.getZ();
+  if (f !== 0.0) {
+    writer.writeFloat(4, f);
+  }
+  f = message.getKind();
+  if (f !== 0.0) {
+    writer.writeEnum(5, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.Bread.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Bread} returns this
+ */
+proto.Bread.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional float x = 2;
+ * @return {number}
+ */
+proto.Bread.prototype.getX = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    2,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Bread} returns this
+ */
+proto.Bread.prototype.setX = function (value) {
+  return Message.setProto3FloatField(this, 2, value);
+};
+
+/**
+ * optional float y = 3;
+ * @return {number}
+ */
+proto.Bread.prototype.getY = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    3,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Bread} returns this
+ */
+proto.Bread.prototype.setY = function (value) {
+  return Message.setProto3FloatField(this, 3, value);
+};
+
+/**
+ * optional float z = 4;
+ * @return {number}
+ */
+proto.Bread.prototype.getZ = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    4,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Bread} returns this
+ */
+proto.Bread.prototype.setZ = function (value) {
+  return Message.setProto3FloatField(this, 4, value);
+};
+
+/**
+ * optional BreadKind kind = 5;
+ * @return {!proto.BreadKind}
+ */
+proto.Bread.prototype.getKind = function () {
+  return /** @type {!proto.BreadKind} */ Message.getFieldWithDefault(
+    this,
+    5,
+    0,
+  );
+};
+
+/**
+ * @param {!proto.BreadKind} value
+ * @return {!proto.Bread} returns this
+ */
+proto.Bread.prototype.setKind = function (value) {
+  return Message.setProto3EnumField(this, 5, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.BreadWeights.prototype.toObject = function (opt_includeInstance) {
+    return proto.BreadWeights.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.BreadWeights} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.BreadWeights.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        crust: Message.getFieldWithDefault(msg, 1, 0),
+        goldenLoaf: Message.getFieldWithDefault(msg, 2, 0),
+        stale: Message.getFieldWithDefault(msg, 3, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.BreadWeights}
+ */
+proto.BreadWeights.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.BreadWeights();
+  return proto.BreadWeights.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.BreadWeights} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.BreadWeights}
+ */
+proto.BreadWeights.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setCrust(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setGoldenLoaf(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setStale(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.BreadWeights.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.BreadWeights.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.BreadWeights} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.BreadWeights.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getCrust();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getGoldenLoaf();
+  if (f !== 0) {
+    writer.writeUint32(2, f);
+  }
+  f = message.getStale();
+  if (f !== 0) {
+    writer.writeUint32(3, f);
+  }
+};
+
+/**
+ * optional uint32 crust = 1;
+ * @return {number}
+ */
+proto.BreadWeights.prototype.getCrust = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.BreadWeights} returns this
+ */
+proto.BreadWeights.prototype.setCrust = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional uint32 golden_loaf = 2;
+ * @return {number}
+ */
+proto.BreadWeights.prototype.getGoldenLoaf = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.BreadWeights} returns this
+ */
+proto.BreadWeights.prototype.setGoldenLoaf = function (value) {
+  return Message.setProto3IntField(this, 2, value);
+};
+
+/**
+ * optional uint32 stale = 3;
+ * @return {number}
+ */
+proto.BreadWeights.prototype.getStale = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 3, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.BreadWeights} returns this
+ */
+proto.BreadWeights.prototype.setStale = function (value) {
+  return Message.setProto3IntField(this, 3, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.PowerUp.prototype.toObject = function (opt_includeInstance) {
+    return proto.PowerUp.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.PowerUp} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.PowerUp.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+        kind: Message.getFieldWithDefault(msg, 2, 0),
+        x: Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
+        y: Message.getFloatingPointFieldWithDefault(msg, 4, 0.0),
+        z: Message.getFloatingPointFieldWithDefault(msg, 5, 0.0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.PowerUp}
+ */
+proto.PowerUp.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.PowerUp();
+  return proto.PowerUp.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.PowerUp} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.PowerUp}
+ */
+proto.PowerUp.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      case 2:
+        var value = /** @type {!proto.PowerUpKind} */ reader.readEnum();
+        msg.setKind(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setX(value);
+        break;
+      case 4:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setY(value);
+        break;
+      case 5:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setZ(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.PowerUp.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.PowerUp.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.PowerUp} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.PowerUp.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getKind();
+  if (f !== 0.0) {
+    writer.writeEnum(2, f);
+  }
+  f = message.getX();
+  if (f !== 0.0) {
+    writer.writeFloat(3, f);
+  }
+  f = message.getY();
+  if (f !== 0.0) {
+    writer.writeFloat(4, f);
+  }
+  f = message.getZ();
+  if (f !== 0.0) {
+    writer.writeFloat(5, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.PowerUp.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.PowerUp} returns this
+ */
+proto.PowerUp.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional PowerUpKind kind = 2;
+ * @return {!proto.PowerUpKind}
+ */
+proto.PowerUp.prototype.getKind = function () {
+  return /** @type {!proto.PowerUpKind} */ Message.getFieldWithDefault(
+    this,
+    2,
+    0,
+  );
+};
+
+/**
+ * @param {!proto.PowerUpKind} value
+ * @return {!proto.PowerUp} returns this
+ */
+proto.PowerUp.prototype.setKind = function (value) {
+  return Message.setProto3EnumField(this, 2, value);
+};
+
+/**
+ * optional float x = 3;
+ * @return {number}
+ */
+proto.PowerUp.prototype.getX = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    3,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.PowerUp} returns this
+ */
+proto.PowerUp.prototype.setX = function (value) {
+  return Message.setProto3FloatField(this, 3, value);
+};
+
+/**
+ * optional float y = 4;
+ * @return {number}
+ */
+proto.PowerUp.prototype.getY = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    4,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.PowerUp} returns this
+ */
+proto.PowerUp.prototype.setY = function (value) {
+  return Message.setProto3FloatField(this, 4, value);
+};
+
+/**
+ * optional float z = 5;
+ * @return {number}
+ */
+proto.PowerUp.prototype.getZ = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    5,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.PowerUp} returns this
+ */
+proto.PowerUp.prototype.setZ = function (value) {
+  return Message.setProto3FloatField(this, 5, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.DuckEffect.prototype.toObject = function (opt_includeInstance) {
+    return proto.DuckEffect.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.DuckEffect} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.DuckEffect.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        duckId: Message.getFieldWithDefault(msg, 1, 0),
+        kind: Message.getFieldWithDefault(msg, 2, 0),
+        remainingMs: Message.getFieldWithDefault(msg, 3, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.DuckEffect}
+ */
+proto.DuckEffect.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.DuckEffect();
+  return proto.DuckEffect.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.DuckEffect} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.DuckEffect}
+ */
+proto.DuckEffect.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setDuckId(value);
+        break;
+      case 2:
+        var value = /** @type {!proto.PowerUpKind} */ reader.readEnum();
+        msg.setKind(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setRemainingMs(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.DuckEffect.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.DuckEffect.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.DuckEffect} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.DuckEffect.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getDuckId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getKind();
+  if (f !== 0.0) {
+    writer.writeEnum(2, f);
+  }
+  f = message.getRemainingMs();
+  if (f !== 0) {
+    writer.writeUint32(3, f);
+  }
+};
+
+/**
+ * optional uint32 duck_id = 1;
+ * @return {number}
+ */
+proto.DuckEffect.prototype.getDuckId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckEffect} returns this
+ */
+proto.DuckEffect.prototype.setDuckId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional PowerUpKind kind = 2;
+ * @return {!proto.PowerUpKind}
+ */
+proto.DuckEffect.prototype.getKind = function () {
+  return /** @type {!proto.PowerUpKind} */ Message.getFieldWithDefault(
+    this,
+    2,
+    0,
+  );
+};
+
+/**
+ * @param {!proto.PowerUpKind} value
+ * @return {!proto.DuckEffect} returns this
+ */
+proto.DuckEffect.prototype.setKind = function (value) {
+  return Message.setProto3EnumField(this, 2, value);
+};
+
+/**
+ * optional uint32 remaining_ms = 3;
+ * @return {number}
+ */
+proto.DuckEffect.prototype.getRemainingMs = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 3, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckEffect} returns this
+ */
+proto.DuckEffect.prototype.setRemainingMs = function (value) {
+  return Message.setProto3IntField(this, 3, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.DuckCollision.prototype.toObject = function (opt_includeInstance) {
+    return proto.DuckCollision.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.DuckCollision} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.DuckCollision.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        duckA: Message.getFieldWithDefault(msg, 1, 0),
+        duckB: Message.getFieldWithDefault(msg, 2, 0),
+        x: Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
+        z: Message.getFloatingPointFieldWithDefault(msg, 4, 0.0),
+        bumperId: Message.getFieldWithDefault(msg, 5, 0),
+        stolenPoints: Message.getFieldWithDefault(msg, 6, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.DuckCollision}
+ */
+proto.DuckCollision.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.DuckCollision();
+  return proto.DuckCollision.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.DuckCollision} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.DuckCollision}
+ */
+proto.DuckCollision.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setDuckA(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setDuckB(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setX(value);
+        break;
+      case 4:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setZ(value);
+        break;
+      case 5:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setBumperId(value);
+        break;
+      case 6:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setStolenPoints(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.DuckCollision.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.DuckCollision.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.DuckCollision} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.DuckCollision.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getDuckA();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getDuckB();
+  if (f !== 0) {
+    writer.writeUint32(2, f);
+  }
+  f = message.getX();
+  if (f !== 0.0) {
+    writer.writeFloat(3, f);
+  }
+  f = message.getZ();
+  if (f !== 0.0) {
+    writer.writeFloat(4, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 5);
+  if (f != null) {
+    writer.writeUint32(5, f);
+  }
+  f = message.getStolenPoints();
+  if (f !== 0) {
+    writer.writeUint32(6, f);
+  }
+};
+
+/**
+ * optional uint32 duck_a = 1;
+ * @return {number}
+ */
+proto.DuckCollision.prototype.getDuckA = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckCollision} returns this
+ */
+proto.DuckCollision.prototype.setDuckA = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional uint32 duck_b = 2;
+ * @return {number}
+ */
+proto.DuckCollision.prototype.getDuckB = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckCollision} returns this
+ */
+proto.DuckCollision.prototype.setDuckB = function (value) {
+  return Message.setProto3IntField(this, 2, value);
+};
+
+/**
+ * optional float x = 3;
+ * @return {number}
+ */
+proto.DuckCollision.prototype.getX = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    3,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckCollision} returns this
+ */
+proto.DuckCollision.prototype.setX = function (value) {
+  return Message.setProto3FloatField(this, 3, value);
+};
+
+/**
+ * optional float z = 4;
+ * @return {number}
+ */
+proto.DuckCollision.prototype.getZ = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    4,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckCollision} returns this
+ */
+proto.DuckCollision.prototype.setZ = function (value) {
+  return Message.setProto3FloatField(this, 4, value);
+};
+
+/**
+ * optional uint32 bumper_id = 5;
+ * @return {number}
+ */
+proto.DuckCollision.prototype.getBumperId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 5, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckCollision} returns this
+ */
+proto.DuckCollision.prototype.setBumperId = function (value) {
+  return Message.setField(this, 5, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.DuckCollision} returns this
+ */
+proto.DuckCollision.prototype.clearBumperId = function () {
+  return Message.setField(this, 5, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.DuckCollision.prototype.hasBumperId = function () {
+  return Message.getField(this, 5) != null;
+};
+
+/**
+ * optional uint32 stolen_points = 6;
+ * @return {number}
+ */
+proto.DuckCollision.prototype.getStolenPoints = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 6, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckCollision} returns this
+ */
+proto.DuckCollision.prototype.setStolenPoints = function (value) {
+  return Message.setProto3IntField(this, 6, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.SlowedDuck.prototype.toObject = function (opt_includeInstance) {
+    return proto.SlowedDuck.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.SlowedDuck} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.SlowedDuck.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        duckId: Message.getFieldWithDefault(msg, 1, 0),
+        remainingMs: Message.getFieldWithDefault(msg, 2, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.SlowedDuck}
+ */
+proto.SlowedDuck.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.SlowedDuck();
+  return proto.SlowedDuck.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.SlowedDuck} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.SlowedDuck}
+ */
+proto.SlowedDuck.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setDuckId(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setRemainingMs(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.SlowedDuck.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.SlowedDuck.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.SlowedDuck} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.SlowedDuck.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getDuckId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getRemainingMs();
+  if (f !== 0) {
+    writer.writeUint32(2, f);
+  }
+};
+
+/**
+ * optional uint32 duck_id = 1;
+ * @return {number}
+ */
+proto.SlowedDuck.prototype.getDuckId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.SlowedDuck} returns this
+ */
+proto.SlowedDuck.prototype.setDuckId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional uint32 remaining_ms = 2;
+ * @return {number}
+ */
+proto.SlowedDuck.prototype.getRemainingMs = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.SlowedDuck} returns this
+ */
+proto.SlowedDuck.prototype.setRemainingMs = function (value) {
+  return Message.setProto3IntField(this, 2, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.TeamScore.prototype.toObject = function (opt_includeInstance) {
+    return proto.TeamScore.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.TeamScore} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.TeamScore.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        team: Message.getFieldWithDefault(msg, 1, 0),
+        score: Message.getFieldWithDefault(msg, 2, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.TeamScore}
+ */
+proto.TeamScore.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.TeamScore();
+  return proto.TeamScore.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.TeamScore} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.TeamScore}
+ */
+proto.TeamScore.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setTeam(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setScore(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.TeamScore.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.TeamScore.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.TeamScore} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.TeamScore.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getTeam();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getScore();
+  if (f !== 0) {
+    writer.writeUint32(2, f);
+  }
+};
+
+/**
+ * optional uint32 team = 1;
+ * @return {number}
+ */
+proto.TeamScore.prototype.getTeam = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.TeamScore} returns this
+ */
+proto.TeamScore.prototype.setTeam = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional uint32 score = 2;
+ * @return {number}
+ */
+proto.TeamScore.prototype.getScore = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.TeamScore} returns this
+ */
+proto.TeamScore.prototype.setScore = function (value) {
+  return Message.setProto3IntField(this, 2, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.BreadPickup.prototype.toObject = function (opt_includeInstance) {
+    return proto.BreadPickup.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.BreadPickup} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.BreadPickup.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        breadId: Message.getFieldWithDefault(msg, 1, 0),
+        duckId: Message.getFieldWithDefault(msg, 2, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.BreadPickup}
+ */
+proto.BreadPickup.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.BreadPickup();
+  return proto.BreadPickup.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.BreadPickup} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.BreadPickup}
+ */
+proto.BreadPickup.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setBreadId(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setDuckId(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.BreadPickup.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.BreadPickup.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.BreadPickup} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.BreadPickup.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getBreadId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getDuckId();
+  if (f !== 0) {
+    writer.writeUint32(2, f);
+  }
+};
+
+/**
+ * optional uint32 bread_id = 1;
+ * @return {number}
+ */
+proto.BreadPickup.prototype.getBreadId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.BreadPickup} returns this
+ */
+proto.BreadPickup.prototype.setBreadId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional uint32 duck_id = 2;
+ * @return {number}
+ */
+proto.BreadPickup.prototype.getDuckId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.BreadPickup} returns this
+ */
+proto.BreadPickup.prototype.setDuckId = function (value) {
+  return Message.setProto3IntField(this, 2, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.DuckDelta.prototype.toObject = function (opt_includeInstance) {
+    return proto.DuckDelta.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.DuckDelta} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.DuckDelta.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+        rotation: Message.getFieldWithDefault(msg, 2, 0),
+        x: Message.getFieldWithDefault(msg, 3, 0),
+        y: Message.getFieldWithDefault(msg, 4, 0),
+        z: Message.getFieldWithDefault(msg, 5, 0),
+        score: Message.getFieldWithDefault(msg, 6, 0),
+        inputSequence: Message.getFieldWithDefault(msg, 7, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.DuckDelta}
+ */
+proto.DuckDelta.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.DuckDelta();
+  return proto.DuckDelta.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.DuckDelta} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.DuckDelta}
+ */
+proto.DuckDelta.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setRotation(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readSint32();
+        msg.setX(value);
+        break;
+      case 4:
+        var value = /** @type {number} */ reader.readSint32();
+        msg.setY(value);
+        break;
+      case 5:
+        var value = /** @type {number} */ reader.readSint32();
+        msg.setZ(value);
+        break;
+      case 6:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setScore(value);
+        break;
+      case 7:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setInputSequence(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.DuckDelta.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.DuckDelta.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.DuckDelta} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.DuckDelta.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 2);
+  if (f != null) {
+    writer.writeUint32(2, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 3);
+  if (f != null) {
+    writer.writeSint32(3, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 4);
+  if (f != null) {
+    writer.writeSint32(4, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 5);
+  if (f != null) {
+    writer.writeSint32(5, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 6);
+  if (f != null) {
+    writer.writeUint32(6, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 7);
+  if (f != null) {
+    writer.writeUint32(7, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.DuckDelta.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional uint32 rotation = 2;
+ * @return {number}
+ */
+proto.DuckDelta.prototype.getRotation = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.setRotation = function (value) {
+  return Message.setField(this, 2, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.clearRotation = function () {
+  return Message.setField(this, 2, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.DuckDelta.prototype.hasRotation = function () {
+  return Message.getField(this, 2) != null;
+};
+
+/**
+ * optional sint32 x = 3;
+ * @return {number}
+ */
+proto.DuckDelta.prototype.getX = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 3, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.setX = function (value) {
+  return Message.setField(this, 3, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.clearX = function () {
+  return Message.setField(this, 3, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.DuckDelta.prototype.hasX = function () {
+  return Message.getField(this, 3) != null;
+};
+
+/**
+ * optional sint32 y = 4;
+ * @return {number}
+ */
+proto.DuckDelta.prototype.getY = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 4, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.setY = function (value) {
+  return Message.setField(this, 4, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.clearY = function () {
+  return Message.setField(this, 4, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.DuckDelta.prototype.hasY = function () {
+  return Message.getField(this, 4) != null;
+};
+
+/**
+ * optional sint32 z = 5;
+ * @return {number}
+ */
+proto.DuckDelta.prototype.getZ = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 5, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.setZ = function (value) {
+  return Message.setField(this, 5, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.clearZ = function () {
+  return Message.setField(this, 5, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.DuckDelta.prototype.hasZ = function () {
+  return Message.getField(this, 5) != null;
+};
+
+/**
+ * optional uint32 score = 6;
+ * @return {number}
+ */
+proto.DuckDelta.prototype.getScore = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 6, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.setScore = function (value) {
+  return Message.setField(this, 6, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.clearScore = function () {
+  return Message.setField(this, 6, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.DuckDelta.prototype.hasScore = function () {
+  return Message.getField(this, 6) != null;
+};
+
+/**
+ * optional uint32 input_sequence = 7;
+ * @return {number}
+ */
+proto.DuckDelta.prototype.getInputSequence = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 7, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.setInputSequence = function (value) {
+  return Message.setField(this, 7, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.DuckDelta} returns this
+ */
+proto.DuckDelta.prototype.clearInputSequence = function () {
+  return Message.setField(this, 7, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.DuckDelta.prototype.hasInputSequence = function () {
+  return Message.getField(this, 7) != null;
+};
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.UpdateSync.repeatedFields_ = [1, 7, 8, 9, 10, 11, 14, 15, 16, 17, 18, 19];
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.UpdateSync.prototype.toObject = function (opt_includeInstance) {
+    return proto.UpdateSync.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.UpdateSync} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.UpdateSync.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        ducksList: Message.toObjectList(
+          msg.getDucksList(),
+          proto.Duck.toObject,
+          includeInstance,
+        ),
+        breadX: Message.getFloatingPointFieldWithDefault(msg, 2, 0.0),
+        breadY: Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
+        breadZ: Message.getFloatingPointFieldWithDefault(msg, 4, 0.0),
+        snapshot: Message.getFieldWithDefault(msg, 5, 0),
+        baseline: Message.getFieldWithDefault(msg, 6, 0),
+        duckDeltasList: Message.toObjectList(
+          msg.getDuckDeltasList(),
+          proto.DuckDelta.toObject,
+          includeInstance,
+        ),
+        removedDuckIdsList:
+          (f = Message.getRepeatedField(msg, 8)) == null ? undefined : f,
+        spawnedBreadList: Message.toObjectList(
+          msg.getSpawnedBreadList(),
+          proto.Bread.toObject,
+          includeInstance,
+        ),
+        consumedBreadIdsList:
+          (f = Message.getRepeatedField(msg, 10)) == null ? undefined : f,
+        breadPickupsList: Message.toObjectList(
+          msg.getBreadPickupsList(),
+          proto.BreadPickup.toObject,
+          includeInstance,
+        ),
+        fullBread: Message.getBooleanFieldWithDefault(msg, 12, false),
+        tick: Message.getFieldWithDefault(msg, 13, 0),
+        teamScoresList: Message.toObjectList(
+          msg.getTeamScoresList(),
+          proto.TeamScore.toObject,
+          includeInstance,
+        ),
+        powerUpsList: Message.toObjectList(
+          msg.getPowerUpsList(),
+          proto.PowerUp.toObject,
+          includeInstance,
+        ),
+        duckEffectsList: Message.toObjectList(
+          msg.getDuckEffectsList(),
+          proto.DuckEffect.toObject,
+          includeInstance,
+        ),
+        slowedDucksList: Message.toObjectList(
+          msg.getSlowedDucksList(),
+          proto.SlowedDuck.toObject,
+          includeInstance,
+        ),
+        duckCollisionsList: Message.toObjectList(
+          msg.getDuckCollisionsList(),
+          proto.DuckCollision.toObject,
+          includeInstance,
+        ),
+        movedBreadList: Message.toObjectList(
+          msg.getMovedBreadList(),
+          proto.Bread.toObject,
+          includeInstance,
+        ),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.UpdateSync}
+ */
+proto.UpdateSync.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.UpdateSync();
+  return proto.UpdateSync.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.UpdateSync} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.UpdateSync}
+ */
+proto.UpdateSync.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = new proto.Duck();
+        reader.readMessage(value, proto.Duck.deserializeBinaryFromReader);
+        msg.addDucks(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setBreadX(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setBreadY(value);
+        break;
+      case 4:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setBreadZ(value);
+        break;
+      case 5:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setSnapshot(value);
+        break;
+      case 6:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setBaseline(value);
+        break;
+      case 7:
+        var value = new proto.DuckDelta();
+        reader.readMessage(value, proto.DuckDelta.deserializeBinaryFromReader);
+        msg.addDuckDeltas(value);
+        break;
+      case 8:
+        var values = /** @type {!Array<number>} */ reader.isDelimited()
+          ? reader.readPackedUint32()
+          : [reader.readUint32()];
+        for (var i = 0; i < values.length; i++) {
+          msg.addRemovedDuckIds(values[i]);
+        }
+        break;
+      case 9:
+        var value = new proto.Bread();
+        reader.readMessage(value, proto.Bread.deserializeBinaryFromReader);
+        msg.addSpawnedBread(value);
+        break;
+      case 10:
+        var values = /** @type {!Array<number>} */ reader.isDelimited()
+          ? reader.readPackedUint32()
+          : [reader.readUint32()];
+        for (var i = 0; i < values.length; i++) {
+          msg.addConsumedBreadIds(values[i]);
+        }
+        break;
+      case 11:
+        var value = new proto.BreadPickup();
+        reader.readMessage(
+          value,
+          proto.BreadPickup.deserializeBinaryFromReader,
+        );
+        msg.addBreadPickups(value);
+        break;
+      case 12:
+        var value = /** @type {boolean} */ reader.readBool();
+        msg.setFullBread(value);
+        break;
+      case 13:
+        var value = /** @type {number} */ reader.readUint64();
+        msg.setTick(value);
+        break;
+      case 14:
+        var value = new proto.TeamScore();
+        reader.readMessage(value, proto.TeamScore.deserializeBinaryFromReader);
+        msg.addTeamScores(value);
+        break;
+      case 15:
+        var value = new proto.PowerUp();
+        reader.readMessage(value, proto.PowerUp.deserializeBinaryFromReader);
+        msg.addPowerUps(value);
+        break;
+      case 16:
+        var value = new proto.DuckEffect();
+        reader.readMessage(value, proto.DuckEffect.deserializeBinaryFromReader);
+        msg.addDuckEffects(value);
+        break;
+      case 17:
+        var value = new proto.SlowedDuck();
+        reader.readMessage(value, proto.SlowedDuck.deserializeBinaryFromReader);
+        msg.addSlowedDucks(value);
+        break;
+      case 18:
+        var value = new proto.DuckCollision();
+        reader.readMessage(
+          value,
+          proto.DuckCollision.deserializeBinaryFromReader,
+        );
+        msg.addDuckCollisions(value);
+        break;
+      case 19:
+        var value = new proto.Bread();
+        reader.readMessage(value, proto.Bread.deserializeBinaryFromReader);
+        msg.addMovedBread(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.UpdateSync.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.UpdateSync.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.UpdateSync} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.UpdateSync.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getDucksList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(1, f, proto.Duck.serializeBinaryToWriter);
+  }
+  f = /** @type {number} */ Message.getField(message, 2);
+  if (f != null) {
+    writer.writeFloat(2, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 3);
+  if (f != null) {
+    writer.writeFloat(3, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 4);
+  if (f != null) {
+    writer.writeFloat(4, f);
+  }
+  f = message.getSnapshot();
+  if (f !== 0) {
+    writer.writeUint32(5, f);
+  }
+  f = message.getBaseline();
+  if (f !== 0) {
+    writer.writeUint32(6, f);
+  }
+  f = message.getDuckDeltasList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(7, f, proto.DuckDelta.serializeBinaryToWriter);
+  }
+  f = message.getRemovedDuckIdsList();
+  if (f.length > 0) {
+    writer.writePackedUint32(8, f);
+  }
+  f = message.getSpawnedBreadList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(9, f, proto.Bread.serializeBinaryToWriter);
+  }
+  f = message.getConsumedBreadIdsList();
+  if (f.length > 0) {
+    writer.writePackedUint32(10, f);
+  }
+  f = message.getBreadPickupsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      11,
+      f,
+      proto.BreadPickup.serializeBinaryToWriter,
+    );
+  }
+  f = message.getFullBread();
+  if (f) {
+    writer.writeBool(12, f);
+  }
+  f = message.getTick();
+  if (f !== 0) {
+    writer.writeUint64(13, f);
+  }
+  f = message.getTeamScoresList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(14, f, proto.TeamScore.serializeBinaryToWriter);
+  }
+  f = message.getPowerUpsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(15, f, proto.PowerUp.serializeBinaryToWriter);
+  }
+  f = message.getDuckEffectsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      16,
+      f,
+      proto.DuckEffect.serializeBinaryToWriter,
+    );
+  }
+  f = message.getSlowedDucksList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      17,
+      f,
+      proto.SlowedDuck.serializeBinaryToWriter,
+    );
+  }
+  f = message.getDuckCollisionsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      18,
+      f,
+      proto.DuckCollision.serializeBinaryToWriter,
+    );
+  }
+  f = message.getMovedBreadList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(19, f, proto.Bread.serializeBinaryToWriter);
+  }
+};
+
+/**
+ * repeated Duck ducks = 1;
+ * @return {!Array<!proto.Duck>}
+ */
+proto.UpdateSync.prototype.getDucksList = function () {
+  return /** @type{!Array<!proto.Duck>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.Duck,
+    1,
+  );
+};
+
+/**
+ * @param {!Array<!proto.Duck>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setDucksList = function (value) {
+  return Message.setRepeatedWrapperField(this, 1, value);
+};
+
+/**
+ * @param {!proto.Duck=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.Duck}
+ */
+proto.UpdateSync.prototype.addDucks = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    1,
+    opt_value,
+    proto.Duck,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearDucksList = function () {
+  return this.setDucksList([]);
+};
+
+/**
+ * optional float bread_x = 2;
+ * @return {number}
+ */
+proto.UpdateSync.prototype.getBreadX = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    2,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setBreadX = function (value) {
+  return Message.setField(this, 2, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearBreadX = function () {
+  return Message.setField(this, 2, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.UpdateSync.prototype.hasBreadX = function () {
+  return Message.getField(this, 2) != null;
+};
+
+/**
+ * optional float bread_y = 3;
+ * @return {number}
+ */
+proto.UpdateSync.prototype.getBreadY = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    3,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setBreadY = function (value) {
+  return Message.setField(this, 3, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearBreadY = function () {
+  return Message.setField(this, 3, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.UpdateSync.prototype.hasBreadY = function () {
+  return Message.getField(this, 3) != null;
+};
+
+/**
+ * optional float bread_z = 4;
+ * @return {number}
+ */
+proto.UpdateSync.prototype.getBreadZ = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    4,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setBreadZ = function (value) {
+  return Message.setField(this, 4, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearBreadZ = function () {
+  return Message.setField(this, 4, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.UpdateSync.prototype.hasBreadZ = function () {
+  return Message.getField(this, 4) != null;
+};
+
+/**
+ * optional uint32 snapshot = 5;
+ * @return {number}
+ */
+proto.UpdateSync.prototype.getSnapshot = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 5, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setSnapshot = function (value) {
+  return Message.setProto3IntField(this, 5, value);
+};
+
+/**
+ * optional uint32 baseline = 6;
+ * @return {number}
+ */
+proto.UpdateSync.prototype.getBaseline = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 6, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setBaseline = function (value) {
+  return Message.setProto3IntField(this, 6, value);
+};
+
+/**
+ * repeated DuckDelta duck_deltas = 7;
+ * @return {!Array<!proto.DuckDelta>}
+ */
+proto.UpdateSync.prototype.getDuckDeltasList = function () {
+  return /** @type{!Array<!proto.DuckDelta>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.DuckDelta,
+    7,
+  );
+};
+
+/**
+ * @param {!Array<!proto.DuckDelta>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setDuckDeltasList = function (value) {
+  return Message.setRepeatedWrapperField(this, 7, value);
+};
+
+/**
+ * @param {!proto.DuckDelta=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.DuckDelta}
+ */
+proto.UpdateSync.prototype.addDuckDeltas = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    7,
+    opt_value,
+    proto.DuckDelta,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearDuckDeltasList = function () {
+  return this.setDuckDeltasList([]);
+};
+
+/**
+ * repeated uint32 removed_duck_ids = 8;
+ * @return {!Array<number>}
+ */
+proto.UpdateSync.prototype.getRemovedDuckIdsList = function () {
+  return /** @type {!Array<number>} */ Message.getRepeatedField(this, 8);
+};
+
+/**
+ * @param {!Array<number>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setRemovedDuckIdsList = function (value) {
+  return Message.setField(this, 8, value || []);
+};
+
+/**
+ * @param {number} value
+ * @param {number=} opt_index
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.addRemovedDuckIds = function (value, opt_index) {
+  return Message.addToRepeatedField(this, 8, value, opt_index);
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearRemovedDuckIdsList = function () {
+  return this.setRemovedDuckIdsList([]);
+};
+
+/**
+ * repeated Bread spawned_bread = 9;
+ * @return {!Array<!proto.Bread>}
+ */
+proto.UpdateSync.prototype.getSpawnedBreadList = function () {
+  return /** @type{!Array<!proto.Bread>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.Bread,
+    9,
+  );
+};
+
+/**
+ * @param {!Array<!proto.Bread>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setSpawnedBreadList = function (value) {
+  return Message.setRepeatedWrapperField(this, 9, value);
+};
+
+/**
+ * @param {!proto.Bread=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.Bread}
+ */
+proto.UpdateSync.prototype.addSpawnedBread = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    9,
+    opt_value,
+    proto.Bread,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearSpawnedBreadList = function () {
+  return this.setSpawnedBreadList([]);
+};
+
+/**
+ * repeated uint32 consumed_bread_ids = 10;
+ * @return {!Array<number>}
+ */
+proto.UpdateSync.prototype.getConsumedBreadIdsList = function () {
+  return /** @type {!Array<number>} */ Message.getRepeatedField(this, 10);
+};
+
+/**
+ * @param {!Array<number>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setConsumedBreadIdsList = function (value) {
+  return Message.setField(this, 10, value || []);
+};
+
+/**
+ * @param {number} value
+ * @param {number=} opt_index
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.addConsumedBreadIds = function (value, opt_index) {
+  return Message.addToRepeatedField(this, 10, value, opt_index);
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearConsumedBreadIdsList = function () {
+  return this.setConsumedBreadIdsList([]);
+};
+
+/**
+ * repeated BreadPickup bread_pickups = 11;
+ * @return {!Array<!proto.BreadPickup>}
+ */
+proto.UpdateSync.prototype.getBreadPickupsList = function () {
+  return /** @type{!Array<!proto.BreadPickup>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.BreadPickup,
+    11,
+  );
+};
+
+/**
+ * @param {!Array<!proto.BreadPickup>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setBreadPickupsList = function (value) {
+  return Message.setRepeatedWrapperField(this, 11, value);
+};
+
+/**
+ * @param {!proto.BreadPickup=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.BreadPickup}
+ */
+proto.UpdateSync.prototype.addBreadPickups = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    11,
+    opt_value,
+    proto.BreadPickup,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearBreadPickupsList = function () {
+  return this.setBreadPickupsList([]);
+};
+
+/**
+ * optional bool full_bread = 12;
+ * @return {boolean}
+ */
+proto.UpdateSync.prototype.getFullBread = function () {
+  return /** @type {boolean} */ Message.getBooleanFieldWithDefault(
+    this,
+    12,
+    false,
+  );
+};
+
+/**
+ * @param {boolean} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setFullBread = function (value) {
+  return Message.setProto3BooleanField(this, 12, value);
+};
+
+/**
+ * optional uint64 tick = 13;
+ * @return {number}
+ */
+proto.UpdateSync.prototype.getTick = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 13, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setTick = function (value) {
+  return Message.setProto3IntField(this, 13, value);
+};
+
+/**
+ * repeated TeamScore team_scores = 14;
+ * @return {!Array<!proto.TeamScore>}
+ */
+proto.UpdateSync.prototype.getTeamScoresList = function () {
+  return /** @type{!Array<!proto.TeamScore>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.TeamScore,
+    14,
+  );
+};
+
+/**
+ * @param {!Array<!proto.TeamScore>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setTeamScoresList = function (value) {
+  return Message.setRepeatedWrapperField(this, 14, value);
+};
+
+/**
+ * @param {!proto.TeamScore=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.TeamScore}
+ */
+proto.UpdateSync.prototype.addTeamScores = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    14,
+    opt_value,
+    proto.TeamScore,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearTeamScoresList = function () {
+  return this.setTeamScoresList([]);
+};
+
+/**
+ * repeated PowerUp power_ups = 15;
+ * @return {!Array<!proto.PowerUp>}
+ */
+proto.UpdateSync.prototype.getPowerUpsList = function () {
+  return /** @type{!Array<!proto.PowerUp>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.PowerUp,
+    15,
+  );
+};
+
+/**
+ * @param {!Array<!proto.PowerUp>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setPowerUpsList = function (value) {
+  return Message.setRepeatedWrapperField(this, 15, value);
+};
+
+/**
+ * @param {!proto.PowerUp=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.PowerUp}
+ */
+proto.UpdateSync.prototype.addPowerUps = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    15,
+    opt_value,
+    proto.PowerUp,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearPowerUpsList = function () {
+  return this.setPowerUpsList([]);
+};
+
+/**
+ * repeated DuckEffect duck_effects = 16;
+ * @return {!Array<!proto.DuckEffect>}
+ */
+proto.UpdateSync.prototype.getDuckEffectsList = function () {
+  return /** @type{!Array<!proto.DuckEffect>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.DuckEffect,
+    16,
+  );
+};
+
+/**
+ * @param {!Array<!proto.DuckEffect>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setDuckEffectsList = function (value) {
+  return Message.setRepeatedWrapperField(this, 16, value);
+};
+
+/**
+ * @param {!proto.DuckEffect=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.DuckEffect}
+ */
+proto.UpdateSync.prototype.addDuckEffects = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    16,
+    opt_value,
+    proto.DuckEffect,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearDuckEffectsList = function () {
+  return this.setDuckEffectsList([]);
+};
+
+/**
+ * repeated SlowedDuck slowed_ducks = 17;
+ * @return {!Array<!proto.SlowedDuck>}
+ */
+proto.UpdateSync.prototype.getSlowedDucksList = function () {
+  return /** @type{!Array<!proto.SlowedDuck>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.SlowedDuck,
+    17,
+  );
+};
+
+/**
+ * @param {!Array<!proto.SlowedDuck>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setSlowedDucksList = function (value) {
+  return Message.setRepeatedWrapperField(this, 17, value);
+};
+
+/**
+ * @param {!proto.SlowedDuck=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.SlowedDuck}
+ */
+proto.UpdateSync.prototype.addSlowedDucks = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    17,
+    opt_value,
+    proto.SlowedDuck,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearSlowedDucksList = function () {
+  return this.setSlowedDucksList([]);
+};
+
+/**
+ * repeated DuckCollision duck_collisions = 18;
+ * @return {!Array<!proto.DuckCollision>}
+ */
+proto.UpdateSync.prototype.getDuckCollisionsList = function () {
+  return /** @type{!Array<!proto.DuckCollision>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.DuckCollision,
+    18,
+  );
+};
+
+/**
+ * @param {!Array<!proto.DuckCollision>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setDuckCollisionsList = function (value) {
+  return Message.setRepeatedWrapperField(this, 18, value);
+};
+
+/**
+ * @param {!proto.DuckCollision=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.DuckCollision}
+ */
+proto.UpdateSync.prototype.addDuckCollisions = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    18,
+    opt_value,
+    proto.DuckCollision,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearDuckCollisionsList = function () {
+  return this.setDuckCollisionsList([]);
+};
+
+/**
+ * repeated Bread moved_bread = 19;
+ * @return {!Array<!proto.Bread>}
+ */
+proto.UpdateSync.prototype.getMovedBreadList = function () {
+  return /** @type{!Array<!proto.Bread>} */ Message.getRepeatedWrapperField(
+    this,
+    proto.Bread,
+    19,
+  );
+};
+
+/**
+ * @param {!Array<!proto.Bread>} value
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.setMovedBreadList = function (value) {
+  return Message.setRepeatedWrapperField(this, 19, value);
+};
+
+/**
+ * @param {!proto.Bread=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.Bread}
+ */
+proto.UpdateSync.prototype.addMovedBread = function (opt_value, opt_index) {
+  return Message.addToRepeatedWrapperField(
+    this,
+    19,
+    opt_value,
+    proto.Bread,
+    opt_index,
+  );
+};
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.UpdateSync} returns this
+ */
+proto.UpdateSync.prototype.clearMovedBreadList = function () {
+  return this.setMovedBreadList([]);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.Hello.prototype.toObject = function (opt_includeInstance) {
+    return proto.Hello.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.Hello} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.Hello.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        protocolVersion: Message.getFieldWithDefault(msg, 1, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.Hello}
+ */
+proto.Hello.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.Hello();
+  return proto.Hello.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.Hello} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.Hello}
+ */
+proto.Hello.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setProtocolVersion(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.Hello.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.Hello.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.Hello} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.Hello.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getProtocolVersion();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+};
+
+/**
+ * optional uint32 protocol_version = 1;
+ * @return {number}
+ */
+proto.Hello.prototype.getProtocolVersion = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.Hello} returns this
+ */
+proto.Hello.prototype.setProtocolVersion = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.JoinGameRequest.prototype.toObject = function (opt_includeInstance) {
+    return proto.JoinGameRequest.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.JoinGameRequest} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.JoinGameRequest.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        name: Message.getFieldWithDefault(msg, 1, ""),
+        variety: Message.getFieldWithDefault(msg, 2, ""),
+        color: Message.getFieldWithDefault(msg, 3, ""),
+        team: Message.getFieldWithDefault(msg, 4, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.JoinGameRequest}
+ */
+proto.JoinGameRequest.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.JoinGameRequest();
+  return proto.JoinGameRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.JoinGameRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.JoinGameRequest}
+ */
+proto.JoinGameRequest.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {string} */ reader.readString();
+        msg.setName(value);
+        break;
+      case 2:
+        var value = /** @type {string} */ reader.readString();
+        msg.setVariety(value);
+        break;
+      case 3:
+        var value = /** @type {string} */ reader.readString();
+        msg.setColor(value);
+        break;
+      case 4:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setTeam(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.JoinGameRequest.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.JoinGameRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.JoinGameRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.JoinGameRequest.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getName();
+  if (f.length > 0) {
+    writer.writeString(1, f);
+  }
+  f = message.getVariety();
+  if (f.length > 0) {
+    writer.writeString(2, f);
+  }
+  f = message.getColor();
+  if (f.length > 0) {
+    writer.writeString(3, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 4);
+  if (f != null) {
+    writer.writeUint32(4, f);
+  }
+};
+
+/**
+ * optional string name = 1;
+ * @return {string}
+ */
+proto.JoinGameRequest.prototype.getName = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 1, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.JoinGameRequest} returns this
+ */
+proto.JoinGameRequest.prototype.setName = function (value) {
+  return Message.setProto3StringField(this, 1, value);
+};
+
+/**
+ * optional string variety = 2;
+ * @return {string}
+ */
+proto.JoinGameRequest.prototype.getVariety = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 2, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.JoinGameRequest} returns this
+ */
+proto.JoinGameRequest.prototype.setVariety = function (value) {
+  return Message.setProto3StringField(this, 2, value);
+};
+
+/**
+ * optional string color = 3;
+ * @return {string}
+ */
+proto.JoinGameRequest.prototype.getColor = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 3, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.JoinGameRequest} returns this
+ */
+proto.JoinGameRequest.prototype.setColor = function (value) {
+  return Message.setProto3StringField(this, 3, value);
+};
+
+/**
+ * optional uint32 team = 4;
+ * @return {number}
+ */
+proto.JoinGameRequest.prototype.getTeam = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 4, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.JoinGameRequest} returns this
+ */
+proto.JoinGameRequest.prototype.setTeam = function (value) {
+  return Message.setField(this, 4, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.JoinGameRequest} returns this
+ */
+proto.JoinGameRequest.prototype.clearTeam = function () {
+  return Message.setField(this, 4, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.JoinGameRequest.prototype.hasTeam = function () {
+  return Message.getField(this, 4) != null;
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.VoteStartGameRequest.prototype.toObject = function (
+    opt_includeInstance,
+  ) {
+    return proto.VoteStartGameRequest.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.VoteStartGameRequest} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.VoteStartGameRequest.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        vote: Message.getBooleanFieldWithDefault(msg, 1, false),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.VoteStartGameRequest}
+ */
+proto.VoteStartGameRequest.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.VoteStartGameRequest();
+  return proto.VoteStartGameRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.VoteStartGameRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.VoteStartGameRequest}
+ */
+proto.VoteStartGameRequest.deserializeBinaryFromReader = function (
+  msg,
+  reader,
+) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {boolean} */ reader.readBool();
+        msg.setVote(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.VoteStartGameRequest.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.VoteStartGameRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.VoteStartGameRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.VoteStartGameRequest.serializeBinaryToWriter = function (
+  message,
+  writer,
+) {
+  var f = undefined;
+  f = message.getVote();
+  if (f) {
+    writer.writeBool(1, f);
+  }
+};
+
+/**
+ * optional bool vote = 1;
+ * @return {boolean}
+ */
+proto.VoteStartGameRequest.prototype.getVote = function () {
+  return /** @type {boolean} */ Message.getBooleanFieldWithDefault(
+    this,
+    1,
+    false,
+  );
+};
+
+/**
+ * @param {boolean} value
+ * @return {!proto.VoteStartGameRequest} returns this
+ */
+proto.VoteStartGameRequest.prototype.setVote = function (value) {
+  return Message.setProto3BooleanField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.ResumeGameRequest.prototype.toObject = function (opt_includeInstance) {
+    return proto.ResumeGameRequest.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.ResumeGameRequest} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.ResumeGameRequest.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        resumeToken: Message.getFieldWithDefault(msg, 1, ""),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.ResumeGameRequest}
+ */
+proto.ResumeGameRequest.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.ResumeGameRequest();
+  return proto.ResumeGameRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.ResumeGameRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.ResumeGameRequest}
+ */
+proto.ResumeGameRequest.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {string} */ reader.readString();
+        msg.setResumeToken(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.ResumeGameRequest.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.ResumeGameRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.ResumeGameRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.ResumeGameRequest.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getResumeToken();
+  if (f.length > 0) {
+    writer.writeString(1, f);
+  }
+};
+
+/**
+ * optional string resume_token = 1;
+ * @return {string}
+ */
+proto.ResumeGameRequest.prototype.getResumeToken = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 1, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.ResumeGameRequest} returns this
+ */
+proto.ResumeGameRequest.prototype.setResumeToken = function (value) {
+  return Message.setProto3StringField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.StartGameRequest.prototype.toObject = function (opt_includeInstance) {
+    return proto.StartGameRequest.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.StartGameRequest} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.StartGameRequest.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {};
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.StartGameRequest}
+ */
+proto.StartGameRequest.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.StartGameRequest();
+  return proto.StartGameRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.StartGameRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.StartGameRequest}
+ */
+proto.StartGameRequest.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.StartGameRequest.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.StartGameRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.StartGameRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.StartGameRequest.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.KickRequest.prototype.toObject = function (opt_includeInstance) {
+    return proto.KickRequest.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.KickRequest} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.KickRequest.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.KickRequest}
+ */
+proto.KickRequest.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.KickRequest();
+  return proto.KickRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.KickRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.KickRequest}
+ */
+proto.KickRequest.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.KickRequest.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.KickRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.KickRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.KickRequest.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.KickRequest.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.KickRequest} returns this
+ */
+proto.KickRequest.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.SnapshotAck.prototype.toObject = function (opt_includeInstance) {
+    return proto.SnapshotAck.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.SnapshotAck} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.SnapshotAck.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        snapshot: Message.getFieldWithDefault(msg, 1, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.SnapshotAck}
+ */
+proto.SnapshotAck.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.SnapshotAck();
+  return proto.SnapshotAck.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.SnapshotAck} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.SnapshotAck}
+ */
+proto.SnapshotAck.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setSnapshot(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.SnapshotAck.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.SnapshotAck.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.SnapshotAck} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.SnapshotAck.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getSnapshot();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+};
+
+/**
+ * optional uint32 snapshot = 1;
+ * @return {number}
+ */
+proto.SnapshotAck.prototype.getSnapshot = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.SnapshotAck} returns this
+ */
+proto.SnapshotAck.prototype.setSnapshot = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.GameSettings.prototype.toObject = function (opt_includeInstance) {
+    return proto.GameSettings.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.GameSettings} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.GameSettings.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        gameDurationSecs: Message.getFieldWithDefault(msg, 1, 0),
+        breadSpawnPerSecond: Message.getFloatingPointFieldWithDefault(
+          msg,
+          2,
+          0.0,
+        ),
+        breadLimit: Message.getFieldWithDefault(msg, 3, 0),
+        map: Message.getFieldWithDefault(msg, 4, ""),
+        breadWeights:
+          (f = msg.getBreadWeights()) &&
+          proto.BreadWeights.toObject(includeInstance, f),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.GameSettings}
+ */
+proto.GameSettings.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.GameSettings();
+  return proto.GameSettings.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.GameSettings} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.GameSettings}
+ */
+proto.GameSettings.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint64();
+        msg.setGameDurationSecs(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readFloat();
+        msg.setBreadSpawnPerSecond(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setBreadLimit(value);
+        break;
+      case 4:
+        var value = /** @type {string} */ reader.readString();
+        msg.setMap(value);
+        break;
+      case 5:
+        var value = new proto.BreadWeights();
+        reader.readMessage(
+          value,
+          proto.BreadWeights.deserializeBinaryFromReader,
+        );
+        msg.setBreadWeights(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.GameSettings.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.GameSettings.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.GameSettings} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.GameSettings.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getGameDurationSecs();
+  if (f !== 0) {
+    writer.writeUint64(1, f);
+  }
+  f = message.getBreadSpawnPerSecond();
+  if (f !== 0.0) {
+    writer.writeFloat(2, f);
+  }
+  f = message.getBreadLimit();
+  if (f !== 0) {
+    writer.writeUint32(3, f);
+  }
+  f = message.getMap();
+  if (f.length > 0) {
+    writer.writeString(4, f);
+  }
+  f = message.getBreadWeights();
+  if (f != null) {
+    writer.writeMessage(5, f, proto.BreadWeights.serializeBinaryToWriter);
+  }
+};
+
+/**
+ * optional uint64 game_duration_secs = 1;
+ * @return {number}
+ */
+proto.GameSettings.prototype.getGameDurationSecs = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.GameSettings} returns this
+ */
+proto.GameSettings.prototype.setGameDurationSecs = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional float bread_spawn_per_second = 2;
+ * @return {number}
+ */
+proto.GameSettings.prototype.getBreadSpawnPerSecond = function () {
+  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+    this,
+    2,
+    0.0,
+  );
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.GameSettings} returns this
+ */
+proto.GameSettings.prototype.setBreadSpawnPerSecond = function (value) {
+  return Message.setProto3FloatField(this, 2, value);
+};
+
+/**
+ * optional uint32 bread_limit = 3;
+ * @return {number}
+ */
+proto.GameSettings.prototype.getBreadLimit = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 3, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.GameSettings} returns this
+ */
+proto.GameSettings.prototype.setBreadLimit = function (value) {
+  return Message.setProto3IntField(this, 3, value);
+};
+
+/**
+ * optional string map = 4;
+ * @return {string}
+ */
+proto.GameSettings.prototype.getMap = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 4, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.GameSettings} returns this
+ */
+proto.GameSettings.prototype.setMap = function (value) {
+  return Message.setProto3StringField(this, 4, value);
+};
+
+/**
+ * optional BreadWeights bread_weights = 5;
+ * @return {?proto.BreadWeights}
+ */
+proto.GameSettings.prototype.getBreadWeights = function () {
+  return /** @type{?proto.BreadWeights} */ Message.getWrapperField(
+    this,
+    proto.BreadWeights,
+    5,
+  );
+};
+
+/**
+ * @param {?proto.BreadWeights|undefined} value
+ * @return {!proto.GameSettings} returns this
+ */
+proto.GameSettings.prototype.setBreadWeights = function (value) {
+  return Message.setWrapperField(this, 5, value);
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.GameSettings} returns this
+ */
+proto.GameSettings.prototype.clearBreadWeights = function () {
+  return this.setBreadWeights(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.GameSettings.prototype.hasBreadWeights = function () {
+  return Message.getField(this, 5) != null;
+};
+
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.ClientMessage.oneofGroups_ = [[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]];
+
+/**
+ * @enum {number}
+ */
+proto.ClientMessage.MessageCase = {
+  MESSAGE_NOT_SET: 0,
+  HELLO: 1,
+  JOIN_GAME: 2,
+  VOTE_START_GAME: 3,
+  RESUME_GAME: 4,
+  UPDATE: 5,
+  INPUT: 6,
+  ACK_SNAPSHOT: 7,
+  CONFIGURE_GAME: 8,
+  START_GAME: 9,
+  KICK: 10,
+};
+
+/**
+ * @return {proto.ClientMessage.MessageCase}
+ */
+proto.ClientMessage.prototype.getMessageCase = function () {
+  return /** @type {proto.ClientMessage.MessageCase} */ Message.computeOneofCase(
+    this,
+    proto.ClientMessage.oneofGroups_[0],
+  );
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.ClientMessage.prototype.toObject = function (opt_includeInstance) {
+    return proto.ClientMessage.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.ClientMessage} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.ClientMessage.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        hello: (f = msg.getHello()) && proto.Hello.toObject(includeInstance, f),
+        joinGame:
+          (f = msg.getJoinGame()) &&
+          proto.JoinGameRequest.toObject(includeInstance, f),
+        voteStartGame:
+          (f = msg.getVoteStartGame()) &&
+          proto.VoteStartGameRequest.toObject(includeInstance, f),
+        resumeGame:
+          (f = msg.getResumeGame()) &&
+          proto.ResumeGameRequest.toObject(includeInstance, f),
+        update:
+          (f = msg.getUpdate()) &&
+          proto.Duck.toObject(includeInstance, f),
+        input:
+          (f = msg.getInput()) &&
+          proto.DuckInput.toObject(includeInstance, f),
+        ackSnapshot:
+          (f = msg.getAckSnapshot()) &&
+          proto.SnapshotAck.toObject(includeInstance, f),
+        configureGame:
+          (f = msg.getConfigureGame()) &&
+          proto.GameSettings.toObject(includeInstance, f),
+        startGame:
+          (f = msg.getStartGame()) &&
+          proto.StartGameRequest.toObject(includeInstance, f),
+        kick:
+          (f = msg.getKick()) &&
+          proto.KickRequest.toObject(includeInstance, f),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.ClientMessage}
+ */
+proto.ClientMessage.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.ClientMessage();
+  return proto.ClientMessage.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.ClientMessage} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.ClientMessage}
+ */
+proto.ClientMessage.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = new proto.Hello();
+        reader.readMessage(value, proto.Hello.deserializeBinaryFromReader);
+        msg.setHello(value);
+        break;
+      case 2:
+        var value = new proto.JoinGameRequest();
+        reader.readMessage(
+          value,
+          proto.JoinGameRequest.deserializeBinaryFromReader,
+        );
+        msg.setJoinGame(value);
+        break;
+      case 3:
+        var value = new proto.VoteStartGameRequest();
+        reader.readMessage(
+          value,
+          proto.VoteStartGameRequest.deserializeBinaryFromReader,
+        );
+        msg.setVoteStartGame(value);
+        break;
+      case 4:
+        var value = new proto.ResumeGameRequest();
+        reader.readMessage(
+          value,
+          proto.ResumeGameRequest.deserializeBinaryFromReader,
+        );
+        msg.setResumeGame(value);
+        break;
+      case 5:
+        var value = new proto.Duck();
+        reader.readMessage(value, proto.Duck.deserializeBinaryFromReader);
+        msg.setUpdate(value);
+        break;
+      case 6:
+        var value = new proto.DuckInput();
+        reader.readMessage(value, proto.DuckInput.deserializeBinaryFromReader);
+        msg.setInput(value);
+        break;
+      case 7:
+        var value = new proto.SnapshotAck();
+        reader.readMessage(
+          value,
+          proto.SnapshotAck.deserializeBinaryFromReader,
+        );
+        msg.setAckSnapshot(value);
+        break;
+      case 8:
+        var value = new proto.GameSettings();
+        reader.readMessage(
+          value,
+          proto.GameSettings.deserializeBinaryFromReader,
+        );
+        msg.setConfigureGame(value);
+        break;
+      case 9:
+        var value = new proto.StartGameRequest();
+        reader.readMessage(
+          value,
+          proto.StartGameRequest.deserializeBinaryFromReader,
+        );
+        msg.setStartGame(value);
+        break;
+      case 10:
+        var value = new proto.KickRequest();
+        reader.readMessage(
+          value,
+          proto.KickRequest.deserializeBinaryFromReader,
+        );
+        msg.setKick(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.ClientMessage.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.ClientMessage.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.ClientMessage} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.ClientMessage.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getHello();
+  if (f != null) {
+    writer.writeMessage(1, f, proto.Hello.serializeBinaryToWriter);
+  }
+  f = message.getJoinGame();
+  if (f != null) {
+    writer.writeMessage(2, f, proto.JoinGameRequest.serializeBinaryToWriter);
+  }
+  f = message.getVoteStartGame();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.VoteStartGameRequest.serializeBinaryToWriter,
+    );
+  }
+  f = message.getResumeGame();
+  if (f != null) {
+    writer.writeMessage(4, f, proto.ResumeGameRequest.serializeBinaryToWriter);
+  }
+  f = message.getUpdate();
+  if (f != null) {
+    writer.writeMessage(5, f, proto.Duck.serializeBinaryToWriter);
+  }
+  f = message.getInput();
+  if (f != null) {
+    writer.writeMessage(6, f, proto.DuckInput.serializeBinaryToWriter);
+  }
+  f = message.getAckSnapshot();
+  if (f != null) {
+    writer.writeMessage(7, f, proto.SnapshotAck.serializeBinaryToWriter);
+  }
+  f = message.getConfigureGame();
+  if (f != null) {
+    writer.writeMessage(8, f, proto.GameSettings.serializeBinaryToWriter);
+  }
+  f = message.getStartGame();
+  if (f != null) {
+    writer.writeMessage(9, f, proto.StartGameRequest.serializeBinaryToWriter);
+  }
+  f = message.getKick();
+  if (f != null) {
+    writer.writeMessage(10, f, proto.KickRequest.serializeBinaryToWriter);
+  }
+};
+
+/**
+ * optional Hello hello = 1;
+ * @return {?proto.Hello}
+ */
+proto.ClientMessage.prototype.getHello = function () {
+  return /** @type{?proto.Hello} */ Message.getWrapperField(
+    this,
+    proto.Hello,
+    1,
+  );
+};
+
+/**
+ * @param {?proto.Hello|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setHello = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    1,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearHello = function () {
+  return this.setHello(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasHello = function () {
+  return Message.getField(this, 1) != null;
+};
+
+/**
+ * optional JoinGameRequest join_game = 2;
+ * @return {?proto.JoinGameRequest}
+ */
+proto.ClientMessage.prototype.getJoinGame = function () {
+  return /** @type{?proto.JoinGameRequest} */ Message.getWrapperField(
+    this,
+    proto.JoinGameRequest,
+    2,
+  );
+};
+
+/**
+ * @param {?proto.JoinGameRequest|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setJoinGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    2,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearJoinGame = function () {
+  return this.setJoinGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasJoinGame = function () {
+  return Message.getField(this, 2) != null;
+};
+
+/**
+ * optional VoteStartGameRequest vote_start_game = 3;
+ * @return {?proto.VoteStartGameRequest}
+ */
+proto.ClientMessage.prototype.getVoteStartGame = function () {
+  return /** @type{?proto.VoteStartGameRequest} */ Message.getWrapperField(
+    this,
+    proto.VoteStartGameRequest,
+    3,
+  );
+};
+
+/**
+ * @param {?proto.VoteStartGameRequest|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setVoteStartGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    3,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearVoteStartGame = function () {
+  return this.setVoteStartGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasVoteStartGame = function () {
+  return Message.getField(this, 3) != null;
+};
+
+/**
+ * optional ResumeGameRequest resume_game = 4;
+ * @return {?proto.ResumeGameRequest}
+ */
+proto.ClientMessage.prototype.getResumeGame = function () {
+  return /** @type{?proto.ResumeGameRequest} */ Message.getWrapperField(
+    this,
+    proto.ResumeGameRequest,
+    4,
+  );
+};
+
+/**
+ * @param {?proto.ResumeGameRequest|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setResumeGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    4,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearResumeGame = function () {
+  return this.setResumeGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasResumeGame = function () {
+  return Message.getField(this, 4) != null;
+};
+
+/**
+ * optional Duck update = 5;
+ * @return {?proto.Duck}
+ */
+proto.ClientMessage.prototype.getUpdate = function () {
+  return /** @type{?proto.Duck} */ Message.getWrapperField(this, proto.Duck, 5);
+};
+
+/**
+ * @param {?proto.Duck|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setUpdate = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    5,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearUpdate = function () {
+  return this.setUpdate(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasUpdate = function () {
+  return Message.getField(this, 5) != null;
+};
+
+/**
+ * optional DuckInput input = 6;
+ * @return {?proto.DuckInput}
+ */
+proto.ClientMessage.prototype.getInput = function () {
+  return /** @type{?proto.DuckInput} */ Message.getWrapperField(
+    this,
+    proto.DuckInput,
+    6,
+  );
+};
+
+/**
+ * @param {?proto.DuckInput|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setInput = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    6,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearInput = function () {
+  return this.setInput(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasInput = function () {
+  return Message.getField(this, 6) != null;
+};
+
+/**
+ * optional SnapshotAck ack_snapshot = 7;
+ * @return {?proto.SnapshotAck}
+ */
+proto.ClientMessage.prototype.getAckSnapshot = function () {
+  return /** @type{?proto.SnapshotAck} */ Message.getWrapperField(
+    this,
+    proto.SnapshotAck,
+    7,
+  );
+};
+
+/**
+ * @param {?proto.SnapshotAck|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setAckSnapshot = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    7,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearAckSnapshot = function () {
+  return this.setAckSnapshot(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasAckSnapshot = function () {
+  return Message.getField(this, 7) != null;
+};
+
+/**
+ * optional GameSettings configure_game = 8;
+ * @return {?proto.GameSettings}
+ */
+proto.ClientMessage.prototype.getConfigureGame = function () {
+  return /** @type{?proto.GameSettings} */ Message.getWrapperField(
+    this,
+    proto.GameSettings,
+    8,
+  );
+};
+
+/**
+ * @param {?proto.GameSettings|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setConfigureGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    8,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearConfigureGame = function () {
+  return this.setConfigureGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasConfigureGame = function () {
+  return Message.getField(this, 8) != null;
+};
+
+/**
+ * optional StartGameRequest start_game = 9;
+ * @return {?proto.StartGameRequest}
+ */
+proto.ClientMessage.prototype.getStartGame = function () {
+  return /** @type{?proto.StartGameRequest} */ Message.getWrapperField(
+    this,
+    proto.StartGameRequest,
+    9,
+  );
+};
+
+/**
+ * @param {?proto.StartGameRequest|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setStartGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    9,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearStartGame = function () {
+  return this.setStartGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasStartGame = function () {
+  return Message.getField(this, 9) != null;
+};
+
+/**
+ * optional KickRequest kick = 10;
+ * @return {?proto.KickRequest}
+ */
+proto.ClientMessage.prototype.getKick = function () {
+  return /** @type{?proto.KickRequest} */ Message.getWrapperField(
+    this,
+    proto.KickRequest,
+    10,
+  );
+};
+
+/**
+ * @param {?proto.KickRequest|undefined} value
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.setKick = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    10,
+    proto.ClientMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ClientMessage} returns this
+ */
+proto.ClientMessage.prototype.clearKick = function () {
+  return this.setKick(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ClientMessage.prototype.hasKick = function () {
+  return Message.getField(this, 10) != null;
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.JoinGameReply.prototype.toObject = function (opt_includeInstance) {
+    return proto.JoinGameReply.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.JoinGameReply} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.JoinGameReply.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+        lobbyCode: Message.getFieldWithDefault(msg, 2, ""),
+        resumeToken: Message.getFieldWithDefault(msg, 3, ""),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.JoinGameReply}
+ */
+proto.JoinGameReply.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.JoinGameReply();
+  return proto.JoinGameReply.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.JoinGameReply} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.JoinGameReply}
+ */
+proto.JoinGameReply.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      case 2:
+        var value = /** @type {string} */ reader.readString();
+        msg.setLobbyCode(value);
+        break;
+      case 3:
+        var value = /** @type {string} */ reader.readString();
+        msg.setResumeToken(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.JoinGameReply.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.JoinGameReply.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.JoinGameReply} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.JoinGameReply.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getLobbyCode();
+  if (f.length > 0) {
+    writer.writeString(2, f);
+  }
+  f = message.getResumeToken();
+  if (f.length > 0) {
+    writer.writeString(3, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.JoinGameReply.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.JoinGameReply} returns this
+ */
+proto.JoinGameReply.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional string lobby_code = 2;
+ * @return {string}
+ */
+proto.JoinGameReply.prototype.getLobbyCode = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 2, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.JoinGameReply} returns this
+ */
+proto.JoinGameReply.prototype.setLobbyCode = function (value) {
+  return Message.setProto3StringField(this, 2, value);
+};
+
+/**
+ * optional string resume_token = 3;
+ * @return {string}
+ */
+proto.JoinGameReply.prototype.getResumeToken = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 3, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.JoinGameReply} returns this
+ */
+proto.JoinGameReply.prototype.setResumeToken = function (value) {
+  return Message.setProto3StringField(this, 3, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.ResumeGameFailed.prototype.toObject = function (opt_includeInstance) {
+    return proto.ResumeGameFailed.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.ResumeGameFailed} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.ResumeGameFailed.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {};
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.ResumeGameFailed}
+ */
+proto.ResumeGameFailed.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.ResumeGameFailed();
+  return proto.ResumeGameFailed.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.ResumeGameFailed} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.ResumeGameFailed}
+ */
+proto.ResumeGameFailed.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.ResumeGameFailed.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.ResumeGameFailed.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.ResumeGameFailed} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.ResumeGameFailed.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.StartGameCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.StartGameCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.StartGameCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.StartGameCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        startTime: Message.getFieldWithDefault(msg, 1, 0),
+        gameDuration: Message.getFieldWithDefault(msg, 2, 0),
+        spectator: Message.getBooleanFieldWithDefault(msg, 3, false),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.StartGameCast}
+ */
+proto.StartGameCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.StartGameCast();
+  return proto.StartGameCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.StartGameCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.StartGameCast}
+ */
+proto.StartGameCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint64();
+        msg.setStartTime(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint64();
+        msg.setGameDuration(value);
+        break;
+      case 3:
+        var value = /** @type {boolean} */ reader.readBool();
+        msg.setSpectator(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.StartGameCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.StartGameCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.StartGameCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.StartGameCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getStartTime();
+  if (f !== 0) {
+    writer.writeUint64(1, f);
+  }
+  f = message.getGameDuration();
+  if (f !== 0) {
+    writer.writeUint64(2, f);
+  }
+  f = message.getSpectator();
+  if (f) {
+    writer.writeBool(3, f);
+  }
+};
+
+/**
+ * optional uint64 start_time = 1;
+ * @return {number}
+ */
+proto.StartGameCast.prototype.getStartTime = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.StartGameCast} returns this
+ */
+proto.StartGameCast.prototype.setStartTime = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional uint64 game_duration = 2;
+ * @return {number}
+ */
+proto.StartGameCast.prototype.getGameDuration = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.StartGameCast} returns this
+ */
+proto.StartGameCast.prototype.setGameDuration = function (value) {
+  return Message.setProto3IntField(this, 2, value);
+};
+
+/**
+ * optional bool spectator = 3;
+ * @return {boolean}
+ */
+proto.StartGameCast.prototype.getSpectator = function () {
+  return /** @type {boolean} */ Message.getBooleanFieldWithDefault(
+    this,
+    3,
+    false,
+  );
+};
+
+/**
+ * @param {boolean} value
+ * @return {!proto.StartGameCast} returns this
+ */
+proto.StartGameCast.prototype.setSpectator = function (value) {
+  return Message.setProto3BooleanField(this, 3, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.EndGameCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.EndGameCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.EndGameCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.EndGameCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        reason: Message.getFieldWithDefault(msg, 1, ""),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.EndGameCast}
+ */
+proto.EndGameCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.EndGameCast();
+  return proto.EndGameCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.EndGameCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.EndGameCast}
+ */
+proto.EndGameCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {string} */ reader.readString();
+        msg.setReason(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.EndGameCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.EndGameCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.EndGameCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.EndGameCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getReason();
+  if (f.length > 0) {
+    writer.writeString(1, f);
+  }
+};
+
+/**
+ * optional string reason = 1;
+ * @return {string}
+ */
+proto.EndGameCast.prototype.getReason = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 1, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.EndGameCast} returns this
+ */
+proto.EndGameCast.prototype.setReason = function (value) {
+  return Message.setProto3StringField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.JoinGameCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.JoinGameCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.JoinGameCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.JoinGameCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+        name: Message.getFieldWithDefault(msg, 2, ""),
+        variety: Message.getFieldWithDefault(msg, 3, ""),
+        color: Message.getFieldWithDefault(msg, 4, ""),
+        spectator: Message.getBooleanFieldWithDefault(msg, 5, false),
+        host: Message.getBooleanFieldWithDefault(msg, 6, false),
+        team: Message.getFieldWithDefault(msg, 7, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.JoinGameCast}
+ */
+proto.JoinGameCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.JoinGameCast();
+  return proto.JoinGameCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.JoinGameCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.JoinGameCast}
+ */
+proto.JoinGameCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      case 2:
+        var value = /** @type {string} */ reader.readString();
+        msg.setName(value);
+        break;
+      case 3:
+        var value = /** @type {string} */ reader.readString();
+        msg.setVariety(value);
+        break;
+      case 4:
+        var value = /** @type {string} */ reader.readString();
+        msg.setColor(value);
+        break;
+      case 5:
+        var value = /** @type {boolean} */ reader.readBool();
+        msg.setSpectator(value);
+        break;
+      case 6:
+        var value = /** @type {boolean} */ reader.readBool();
+        msg.setHost(value);
+        break;
+      case 7:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setTeam(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.JoinGameCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.JoinGameCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.JoinGameCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.JoinGameCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getName();
+  if (f.length > 0) {
+    writer.writeString(2, f);
+  }
+  f = message.getVariety();
+  if (f.length > 0) {
+    writer.writeString(3, f);
+  }
+  f = message.getColor();
+  if (f.length > 0) {
+    writer.writeString(4, f);
+  }
+  f = message.getSpectator();
+  if (f) {
+    writer.writeBool(5, f);
+  }
+  f = message.getHost();
+  if (f) {
+    writer.writeBool(6, f);
+  }
+  f = /** @type {number} */ Message.getField(message, 7);
+  if (f != null) {
+    writer.writeUint32(7, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.JoinGameCast.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.JoinGameCast} returns this
+ */
+proto.JoinGameCast.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional string name = 2;
+ * @return {string}
+ */
+proto.JoinGameCast.prototype.getName = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 2, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.JoinGameCast} returns this
+ */
+proto.JoinGameCast.prototype.setName = function (value) {
+  return Message.setProto3StringField(this, 2, value);
+};
+
+/**
+ * optional string variety = 3;
+ * @return {string}
+ */
+proto.JoinGameCast.prototype.getVariety = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 3, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.JoinGameCast} returns this
+ */
+proto.JoinGameCast.prototype.setVariety = function (value) {
+  return Message.setProto3StringField(this, 3, value);
+};
+
+/**
+ * optional string color = 4;
+ * @return {string}
+ */
+proto.JoinGameCast.prototype.getColor = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 4, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.JoinGameCast} returns this
+ */
+proto.JoinGameCast.prototype.setColor = function (value) {
+  return Message.setProto3StringField(this, 4, value);
+};
+
+/**
+ * optional bool spectator = 5;
+ * @return {boolean}
+ */
+proto.JoinGameCast.prototype.getSpectator = function () {
+  return /** @type {boolean} */ Message.getBooleanFieldWithDefault(
+    this,
+    5,
+    false,
+  );
+};
+
+/**
+ * @param {boolean} value
+ * @return {!proto.JoinGameCast} returns this
+ */
+proto.JoinGameCast.prototype.setSpectator = function (value) {
+  return Message.setProto3BooleanField(this, 5, value);
+};
+
+/**
+ * optional bool host = 6;
+ * @return {boolean}
+ */
+proto.JoinGameCast.prototype.getHost = function () {
+  return /** @type {boolean} */ Message.getBooleanFieldWithDefault(
+    this,
+    6,
+    false,
+  );
+};
+
+/**
+ * @param {boolean} value
+ * @return {!proto.JoinGameCast} returns this
+ */
+proto.JoinGameCast.prototype.setHost = function (value) {
+  return Message.setProto3BooleanField(this, 6, value);
+};
+
+/**
+ * optional uint32 team = 7;
+ * @return {number}
+ */
+proto.JoinGameCast.prototype.getTeam = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 7, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.JoinGameCast} returns this
+ */
+proto.JoinGameCast.prototype.setTeam = function (value) {
+  return Message.setField(this, 7, value);
+};
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.JoinGameCast} returns this
+ */
+proto.JoinGameCast.prototype.clearTeam = function () {
+  return Message.setField(this, 7, undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.JoinGameCast.prototype.hasTeam = function () {
+  return Message.getField(this, 7) != null;
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.LeaveGameCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.LeaveGameCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.LeaveGameCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.LeaveGameCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.LeaveGameCast}
+ */
+proto.LeaveGameCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.LeaveGameCast();
+  return proto.LeaveGameCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.LeaveGameCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.LeaveGameCast}
+ */
+proto.LeaveGameCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.LeaveGameCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.LeaveGameCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.LeaveGameCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.LeaveGameCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.LeaveGameCast.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.LeaveGameCast} returns this
+ */
+proto.LeaveGameCast.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.VoteStatusCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.VoteStatusCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.VoteStatusCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.VoteStatusCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        votes: Message.getFieldWithDefault(msg, 1, 0),
+        ducks: Message.getFieldWithDefault(msg, 2, 0),
+        requiredVotes: Message.getFieldWithDefault(msg, 3, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.VoteStatusCast}
+ */
+proto.VoteStatusCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.VoteStatusCast();
+  return proto.VoteStatusCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.VoteStatusCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.VoteStatusCast}
+ */
+proto.VoteStatusCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setVotes(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setDucks(value);
+        break;
+      case 3:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setRequiredVotes(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.VoteStatusCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.VoteStatusCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.VoteStatusCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.VoteStatusCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getVotes();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+  f = message.getDucks();
+  if (f !== 0) {
+    writer.writeUint32(2, f);
+  }
+  f = message.getRequiredVotes();
+  if (f !== 0) {
+    writer.writeUint32(3, f);
+  }
+};
+
+/**
+ * optional uint32 votes = 1;
+ * @return {number}
+ */
+proto.VoteStatusCast.prototype.getVotes = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.VoteStatusCast} returns this
+ */
+proto.VoteStatusCast.prototype.setVotes = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional uint32 ducks = 2;
+ * @return {number}
+ */
+proto.VoteStatusCast.prototype.getDucks = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.VoteStatusCast} returns this
+ */
+proto.VoteStatusCast.prototype.setDucks = function (value) {
+  return Message.setProto3IntField(this, 2, value);
+};
+
+/**
+ * optional uint32 required_votes = 3;
+ * @return {number}
+ */
+proto.VoteStatusCast.prototype.getRequiredVotes = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 3, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.VoteStatusCast} returns this
+ */
+proto.VoteStatusCast.prototype.setRequiredVotes = function (value) {
+  return Message.setProto3IntField(this, 3, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.HostCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.HostCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.HostCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.HostCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        id: Message.getFieldWithDefault(msg, 1, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.HostCast}
+ */
+proto.HostCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.HostCast();
+  return proto.HostCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.HostCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.HostCast}
+ */
+proto.HostCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {number} */ reader.readUint32();
+        msg.setId(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.HostCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.HostCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.HostCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.HostCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f !== 0) {
+    writer.writeUint32(1, f);
+  }
+};
+
+/**
+ * optional uint32 id = 1;
+ * @return {number}
+ */
+proto.HostCast.prototype.getId = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.HostCast} returns this
+ */
+proto.HostCast.prototype.setId = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.PhaseCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.PhaseCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.PhaseCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.PhaseCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        phase: Message.getFieldWithDefault(msg, 1, 0),
+        remainingMs: Message.getFieldWithDefault(msg, 2, 0),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.PhaseCast}
+ */
+proto.PhaseCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.PhaseCast();
+  return proto.PhaseCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.PhaseCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.PhaseCast}
+ */
+proto.PhaseCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {!proto.Phase} */ reader.readEnum();
+        msg.setPhase(value);
+        break;
+      case 2:
+        var value = /** @type {number} */ reader.readUint64();
+        msg.setRemainingMs(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.PhaseCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.PhaseCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.PhaseCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.PhaseCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getPhase();
+  if (f !== 0.0) {
+    writer.writeEnum(1, f);
+  }
+  f = message.getRemainingMs();
+  if (f !== 0) {
+    writer.writeUint64(2, f);
+  }
+};
+
+/**
+ * optional Phase phase = 1;
+ * @return {!proto.Phase}
+ */
+proto.PhaseCast.prototype.getPhase = function () {
+  return /** @type {!proto.Phase} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {!proto.Phase} value
+ * @return {!proto.PhaseCast} returns this
+ */
+proto.PhaseCast.prototype.setPhase = function (value) {
+  return Message.setProto3EnumField(this, 1, value);
+};
+
+/**
+ * optional uint64 remaining_ms = 2;
+ * @return {number}
+ */
+proto.PhaseCast.prototype.getRemainingMs = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 2, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.PhaseCast} returns this
+ */
+proto.PhaseCast.prototype.setRemainingMs = function (value) {
+  return Message.setProto3IntField(this, 2, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.KickCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.KickCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.KickCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.KickCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        reason: Message.getFieldWithDefault(msg, 1, ""),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.KickCast}
+ */
+proto.KickCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.KickCast();
+  return proto.KickCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.KickCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.KickCast}
+ */
+proto.KickCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {string} */ reader.readString();
+        msg.setReason(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.KickCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.KickCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.KickCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.KickCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getReason();
+  if (f.length > 0) {
+    writer.writeString(1, f);
+  }
+};
+
+/**
+ * optional string reason = 1;
+ * @return {string}
+ */
+proto.KickCast.prototype.getReason = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 1, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.KickCast} returns this
+ */
+proto.KickCast.prototype.setReason = function (value) {
+  return Message.setProto3StringField(this, 1, value);
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.ErrorCast.prototype.toObject = function (opt_includeInstance) {
+    return proto.ErrorCast.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.ErrorCast} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.ErrorCast.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        message: Message.getFieldWithDefault(msg, 1, ""),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.ErrorCast}
+ */
+proto.ErrorCast.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.ErrorCast();
+  return proto.ErrorCast.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.ErrorCast} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.ErrorCast}
+ */
+proto.ErrorCast.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = /** @type {string} */ reader.readString();
+        msg.setMessage(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.ErrorCast.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.ErrorCast.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.ErrorCast} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.ErrorCast.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getMessage();
+  if (f.length > 0) {
+    writer.writeString(1, f);
+  }
+};
+
+/**
+ * optional string message = 1;
+ * @return {string}
+ */
+proto.ErrorCast.prototype.getMessage = function () {
+  return /** @type {string} */ Message.getFieldWithDefault(this, 1, "");
+};
+
+/**
+ * @param {string} value
+ * @return {!proto.ErrorCast} returns this
+ */
+proto.ErrorCast.prototype.setMessage = function (value) {
+  return Message.setProto3StringField(this, 1, value);
+};
+
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.ServerMessage.oneofGroups_ = [
+  [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+];
+
+/**
+ * @enum {number}
+ */
+proto.ServerMessage.MessageCase = {
+  MESSAGE_NOT_SET: 0,
+  HELLO: 1,
+  JOIN_GAME: 2,
+  RESUME_GAME_FAILED: 3,
+  START_GAME: 4,
+  END_GAME: 5,
+  JOIN_GAME_CAST: 6,
+  LEAVE_GAME_CAST: 7,
+  VOTE_STATUS: 8,
+  KICK: 9,
+  UPDATE_SYNC: 10,
+  ERROR: 11,
+  GAME_SETTINGS: 12,
+  HOST: 13,
+  PHASE: 14,
+};
+
+/**
+ * @return {proto.ServerMessage.MessageCase}
+ */
+proto.ServerMessage.prototype.getMessageCase = function () {
+  return /** @type {proto.ServerMessage.MessageCase} */ Message.computeOneofCase(
+    this,
+    proto.ServerMessage.oneofGroups_[0],
+  );
+};
+
+if (Message.GENERATE_TO_OBJECT) {
+  /**
+   * Creates an object representation of this proto.
+   * Field names that are reserved in JavaScript and will be renamed to pb_name.
+   * Optional fields that are not set will be set to undefined.
+   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+   * For the list of reserved names please see:
+   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+   *     JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @return {!Object}
+   */
+  proto.ServerMessage.prototype.toObject = function (opt_includeInstance) {
+    return proto.ServerMessage.toObject(opt_includeInstance, this);
+  };
+
+  /**
+   * Static version of the {@see toObject} method.
+   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+   *     the JSPB instance for transitional soy proto support:
+   *     http://goto/soy-param-migration
+   * @param {!proto.ServerMessage} msg The msg instance to transform.
+   * @return {!Object}
+   * @suppress {unusedLocalVariables} f is only used for nested messages
+   */
+  proto.ServerMessage.toObject = function (includeInstance, msg) {
+    var f,
+      obj = {
+        hello: (f = msg.getHello()) && proto.Hello.toObject(includeInstance, f),
+        joinGame:
+          (f = msg.getJoinGame()) &&
+          proto.JoinGameReply.toObject(includeInstance, f),
+        resumeGameFailed:
+          (f = msg.getResumeGameFailed()) &&
+          proto.ResumeGameFailed.toObject(includeInstance, f),
+        startGame:
+          (f = msg.getStartGame()) &&
+          proto.StartGameCast.toObject(includeInstance, f),
+        endGame:
+          (f = msg.getEndGame()) &&
+          proto.EndGameCast.toObject(includeInstance, f),
+        joinGameCast:
+          (f = msg.getJoinGameCast()) &&
+          proto.JoinGameCast.toObject(includeInstance, f),
+        leaveGameCast:
+          (f = msg.getLeaveGameCast()) &&
+          proto.LeaveGameCast.toObject(includeInstance, f),
+        voteStatus:
+          (f = msg.getVoteStatus()) &&
+          proto.VoteStatusCast.toObject(includeInstance, f),
+        kick:
+          (f = msg.getKick()) &&
+          proto.KickCast.toObject(includeInstance, f),
+        updateSync:
+          (f = msg.getUpdateSync()) &&
+          proto.UpdateSync.toObject(includeInstance, f),
+        error:
+          (f = msg.getError()) &&
+          proto.ErrorCast.toObject(includeInstance, f),
+        gameSettings:
+          (f = msg.getGameSettings()) &&
+          proto.GameSettings.toObject(includeInstance, f),
+        host:
+          (f = msg.getHost()) &&
+          proto.HostCast.toObject(includeInstance, f),
+        phase:
+          (f = msg.getPhase()) &&
+          proto.PhaseCast.toObject(includeInstance, f),
+      };
+
+    if (includeInstance) {
+      obj.$jspbMessageInstance = msg;
+    }
+    return obj;
+  };
+}
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.ServerMessage}
+ */
+proto.ServerMessage.deserializeBinary = function (bytes) {
+  var reader = new BinaryReader(bytes);
+  var msg = new proto.ServerMessage();
+  return proto.ServerMessage.deserializeBinaryFromReader(msg, reader);
+};
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.ServerMessage} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.ServerMessage}
+ */
+proto.ServerMessage.deserializeBinaryFromReader = function (msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+      case 1:
+        var value = new proto.Hello();
+        reader.readMessage(value, proto.Hello.deserializeBinaryFromReader);
+        msg.setHello(value);
+        break;
+      case 2:
+        var value = new proto.JoinGameReply();
+        reader.readMessage(
+          value,
+          proto.JoinGameReply.deserializeBinaryFromReader,
+        );
+        msg.setJoinGame(value);
+        break;
+      case 3:
+        var value = new proto.ResumeGameFailed();
+        reader.readMessage(
+          value,
+          proto.ResumeGameFailed.deserializeBinaryFromReader,
+        );
+        msg.setResumeGameFailed(value);
+        break;
+      case 4:
+        var value = new proto.StartGameCast();
+        reader.readMessage(
+          value,
+          proto.StartGameCast.deserializeBinaryFromReader,
+        );
+        msg.setStartGame(value);
+        break;
+      case 5:
+        var value = new proto.EndGameCast();
+        reader.readMessage(
+          value,
+          proto.EndGameCast.deserializeBinaryFromReader,
+        );
+        msg.setEndGame(value);
+        break;
+      case 6:
+        var value = new proto.JoinGameCast();
+        reader.readMessage(
+          value,
+          proto.JoinGameCast.deserializeBinaryFromReader,
+        );
+        msg.setJoinGameCast(value);
+        break;
+      case 7:
+        var value = new proto.LeaveGameCast();
+        reader.readMessage(
+          value,
+          proto.LeaveGameCast.deserializeBinaryFromReader,
+        );
+        msg.setLeaveGameCast(value);
+        break;
+      case 8:
+        var value = new proto.VoteStatusCast();
+        reader.readMessage(
+          value,
+          proto.VoteStatusCast.deserializeBinaryFromReader,
+        );
+        msg.setVoteStatus(value);
+        break;
+      case 9:
+        var value = new proto.KickCast();
+        reader.readMessage(value, proto.KickCast.deserializeBinaryFromReader);
+        msg.setKick(value);
+        break;
+      case 10:
+        var value = new proto.UpdateSync();
+        reader.readMessage(value, proto.UpdateSync.deserializeBinaryFromReader);
+        msg.setUpdateSync(value);
+        break;
+      case 11:
+        var value = new proto.ErrorCast();
+        reader.readMessage(value, proto.ErrorCast.deserializeBinaryFromReader);
+        msg.setError(value);
+        break;
+      case 12:
+        var value = new proto.GameSettings();
+        reader.readMessage(
+          value,
+          proto.GameSettings.deserializeBinaryFromReader,
+        );
+        msg.setGameSettings(value);
+        break;
+      case 13:
+        var value = new proto.HostCast();
+        reader.readMessage(value, proto.HostCast.deserializeBinaryFromReader);
+        msg.setHost(value);
+        break;
+      case 14:
+        var value = new proto.PhaseCast();
+        reader.readMessage(value, proto.PhaseCast.deserializeBinaryFromReader);
+        msg.setPhase(value);
+        break;
+      default:
+        reader.skipField();
+        break;
+    }
+  }
+  return msg;
+};
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.ServerMessage.prototype.serializeBinary = function () {
+  var writer = new BinaryWriter();
+  proto.ServerMessage.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.ServerMessage} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.ServerMessage.serializeBinaryToWriter = function (message, writer) {
+  var f = undefined;
+  f = message.getHello();
+  if (f != null) {
+    writer.writeMessage(1, f, proto.Hello.serializeBinaryToWriter);
+  }
+  f = message.getJoinGame();
+  if (f != null) {
+    writer.writeMessage(2, f, proto.JoinGameReply.serializeBinaryToWriter);
+  }
+  f = message.getResumeGameFailed();
+  if (f != null) {
+    writer.writeMessage(3, f, proto.ResumeGameFailed.serializeBinaryToWriter);
+  }
+  f = message.getStartGame();
+  if (f != null) {
+    writer.writeMessage(4, f, proto.StartGameCast.serializeBinaryToWriter);
+  }
+  f = message.getEndGame();
+  if (f != null) {
+    writer.writeMessage(5, f, proto.EndGameCast.serializeBinaryToWriter);
+  }
+  f = message.getJoinGameCast();
+  if (f != null) {
+    writer.writeMessage(6, f, proto.JoinGameCast.serializeBinaryToWriter);
+  }
+  f = message.getLeaveGameCast();
+  if (f != null) {
+    writer.writeMessage(7, f, proto.LeaveGameCast.serializeBinaryToWriter);
+  }
+  f = message.getVoteStatus();
+  if (f != null) {
+    writer.writeMessage(8, f, proto.VoteStatusCast.serializeBinaryToWriter);
+  }
+  f = message.getKick();
+  if (f != null) {
+    writer.writeMessage(9, f, proto.KickCast.serializeBinaryToWriter);
+  }
+  f = message.getUpdateSync();
+  if (f != null) {
+    writer.writeMessage(10, f, proto.UpdateSync.serializeBinaryToWriter);
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(11, f, proto.ErrorCast.serializeBinaryToWriter);
+  }
+  f = message.getGameSettings();
+  if (f != null) {
+    writer.writeMessage(12, f, proto.GameSettings.serializeBinaryToWriter);
+  }
+  f = message.getHost();
+  if (f != null) {
+    writer.writeMessage(13, f, proto.HostCast.serializeBinaryToWriter);
+  }
+  f = message.getPhase();
+  if (f != null) {
+    writer.writeMessage(14, f, proto.PhaseCast.serializeBinaryToWriter);
+  }
+};
+
+/**
+ * optional Hello hello = 1;
+ * @return {?proto.Hello}
+ */
+proto.ServerMessage.prototype.getHello = function () {
+  return /** @type{?proto.Hello} */ Message.getWrapperField(
+    this,
+    proto.Hello,
+    1,
+  );
+};
+
+/**
+ * @param {?proto.Hello|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setHello = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    1,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearHello = function () {
+  return this.setHello(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasHello = function () {
+  return Message.getField(this, 1) != null;
+};
+
+/**
+ * optional JoinGameReply join_game = 2;
+ * @return {?proto.JoinGameReply}
+ */
+proto.ServerMessage.prototype.getJoinGame = function () {
+  return /** @type{?proto.JoinGameReply} */ Message.getWrapperField(
+    this,
+    proto.JoinGameReply,
+    2,
+  );
+};
+
+/**
+ * @param {?proto.JoinGameReply|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setJoinGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    2,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearJoinGame = function () {
+  return this.setJoinGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasJoinGame = function () {
+  return Message.getField(this, 2) != null;
+};
+
+/**
+ * optional ResumeGameFailed resume_game_failed = 3;
+ * @return {?proto.ResumeGameFailed}
+ */
+proto.ServerMessage.prototype.getResumeGameFailed = function () {
+  return /** @type{?proto.ResumeGameFailed} */ Message.getWrapperField(
+    this,
+    proto.ResumeGameFailed,
+    3,
+  );
+};
+
+/**
+ * @param {?proto.ResumeGameFailed|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setResumeGameFailed = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    3,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearResumeGameFailed = function () {
+  return this.setResumeGameFailed(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasResumeGameFailed = function () {
+  return Message.getField(this, 3) != null;
+};
+
+/**
+ * optional StartGameCast start_game = 4;
+ * @return {?proto.StartGameCast}
+ */
+proto.ServerMessage.prototype.getStartGame = function () {
+  return /** @type{?proto.StartGameCast} */ Message.getWrapperField(
+    this,
+    proto.StartGameCast,
+    4,
+  );
+};
+
+/**
+ * @param {?proto.StartGameCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setStartGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    4,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearStartGame = function () {
+  return this.setStartGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasStartGame = function () {
+  return Message.getField(this, 4) != null;
+};
+
+/**
+ * optional EndGameCast end_game = 5;
+ * @return {?proto.EndGameCast}
+ */
+proto.ServerMessage.prototype.getEndGame = function () {
+  return /** @type{?proto.EndGameCast} */ Message.getWrapperField(
+    this,
+    proto.EndGameCast,
+    5,
+  );
+};
+
+/**
+ * @param {?proto.EndGameCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setEndGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    5,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearEndGame = function () {
+  return this.setEndGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasEndGame = function () {
+  return Message.getField(this, 5) != null;
+};
+
+/**
+ * optional JoinGameCast join_game_cast = 6;
+ * @return {?proto.JoinGameCast}
+ */
+proto.ServerMessage.prototype.getJoinGameCast = function () {
+  return /** @type{?proto.JoinGameCast} */ Message.getWrapperField(
+    this,
+    proto.JoinGameCast,
+    6,
+  );
+};
+
+/**
+ * @param {?proto.JoinGameCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setJoinGameCast = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    6,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearJoinGameCast = function () {
+  return this.setJoinGameCast(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasJoinGameCast = function () {
+  return Message.getField(this, 6) != null;
+};
+
+/**
+ * optional LeaveGameCast leave_game_cast = 7;
+ * @return {?proto.LeaveGameCast}
+ */
+proto.ServerMessage.prototype.getLeaveGameCast = function () {
+  return /** @type{?proto.LeaveGameCast} */ Message.getWrapperField(
+    this,
+    proto.LeaveGameCast,
+    7,
   );
 };
-goog.inherits(proto.UpdateSync, Message);
-if (goog.DEBUG && !COMPILED) {
-  /**
-   * @public
-   * @override
-   */
-  proto.UpdateSync.displayName = "proto.UpdateSync";
-}
 
-if (Message.GENERATE_TO_OBJECT) {
-  /**
-   * Creates an object representation of this proto.
-   * Field names that are reserved in JavaScript and will be renamed to pb_name.
-   * Optional fields that are not set will be set to undefined.
-   * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
-   * For the list of reserved names please see:
-   *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
-   * @param {boolean=} opt_includeInstance Deprecated. whether to include the
-   *     JSPB instance for transitional soy proto support:
-   *     http://goto/soy-param-migration
-   * @return {!Object}
-   */
-  proto.Duck.prototype.toObject = function (opt_includeInstance) {
-    return proto.Duck.toObject(opt_includeInstance, this);
-  };
+/**
+ * @param {?proto.LeaveGameCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setLeaveGameCast = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    7,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
 
-  /**
-   * Static version of the {@see toObject} method.
-   * @param {boolean|undefined} includeInstance Deprecated. Whether to include
-   *     the JSPB instance for transitional soy proto support:
-   *     http://goto/soy-param-migration
-   * @param {!proto.Duck} msg The msg instance to transform.
-   * @return {!Object}
-   * @suppress {unusedLocalVariables} f is only used for nested messages
-   */
-  proto.Duck.toObject = function (includeInstance, msg) {
-    var f,
-      obj = {
-        id: Message.getFieldWithDefault(msg, 1, 0),
-        rotation: Message.getFloatingPointFieldWithDefault(msg, 2, 0.0),
-        x: Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
-        y: Message.getFloatingPointFieldWithDefault(msg, 4, 0.0),
-        z: Message.getFloatingPointFieldWithDefault(msg, 5, 0.0),
-        score: Message.getFieldWithDefault(msg, 6, 0),
-      };
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearLeaveGameCast = function () {
+  return this.setLeaveGameCast(undefined);
+};
 
-    if (includeInstance) {
-      obj.$jspbMessageInstance = msg;
-    }
-    return obj;
-  };
-}
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasLeaveGameCast = function () {
+  return Message.getField(this, 7) != null;
+};
 
 /**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.Duck}
+ * optional VoteStatusCast vote_status = 8;
+ * @return {?proto.VoteStatusCast}
  */
-proto.Duck.deserializeBinary = function (bytes) {
-  var reader = new BinaryReader(bytes);
-  var msg = new proto.Duck();
-  return proto.Duck.deserializeBinaryFromReader(msg, reader);
+proto.ServerMessage.prototype.getVoteStatus = function () {
+  return /** @type{?proto.VoteStatusCast} */ Message.getWrapperField(
+    this,
+    proto.VoteStatusCast,
+    8,
+  );
 };
 
 /**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.Duck} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.Duck}
+ * @param {?proto.VoteStatusCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
  */
-proto.Duck.deserializeBinaryFromReader = function (msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-      case 1:
-        var value = /** @type {number} */ reader.readUint32();
-        msg.setId(value);
-        break;
-      case 2:
-        var value = /** @type {number} */ reader.readFloat();
-        msg.setRotation(value);
-        break;
-      case 3:
-        var value = /** @type {number} */ reader.readFloat();
-        msg.setX(value);
-        break;
-      case 4:
-        var value = /** @type {number} */ reader.readFloat();
-        msg.setY(value);
-        break;
-      case 5:
-        var value = /** @type {number} */ reader.readFloat();
-        msg.setZ(value);
-        break;
-      case 6:
-        var value = /** @type {number} */ reader.readUint32();
-        msg.setScore(value);
-        break;
-      default:
-        reader.skipField();
-        break;
-    }
-  }
-  return msg;
+proto.ServerMessage.prototype.setVoteStatus = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    8,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
 };
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
  */
-proto.Duck.prototype.serializeBinary = function () {
-  var writer = new BinaryWriter();
-  proto.Duck.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.ServerMessage.prototype.clearVoteStatus = function () {
+  return this.setVoteStatus(undefined);
 };
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.Duck} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.Duck.serializeBinaryToWriter = function (message, writer) {
-  var f = undefined;
-  f = message.getId();
-  if (f !== 0) {
-    writer.writeUint32(1, f);
-  }
-  f = message.getRotation();
-  if (f !== 0.0) {
-    writer.writeFloat(2, f);
-  }
-  f = message.getX();
-  if (f !== 0.0) {
-    writer.writeFloat(3, f);
-  }
-  f = message.getY();
-  if (f !== 0.0) {
-    writer.writeFloat(4, f);
-  }
-  f = message.getZ();
-  if (f !== 0.0) {
-    writer.writeFloat(5, f);
-  }
-  f = message.getScore();
-  if (f !== 0) {
-    writer.writeUint32(6, f);
-  }
+proto.ServerMessage.prototype.hasVoteStatus = function () {
+  return Message.getField(this, 8) != null;
 };
 
 /**
- * optional uint32 id = 1;
- * @return {number}
+ * optional KickCast kick = 9;
+ * @return {?proto.KickCast}
  */
-proto.Duck.prototype.getId = function () {
-  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+proto.ServerMessage.prototype.getKick = function () {
+  return /** @type{?proto.KickCast} */ Message.getWrapperField(
+    this,
+    proto.KickCast,
+    9,
+  );
 };
 
 /**
- * @param {number} value
- * @return {!proto.Duck} returns this
+ * @param {?proto.KickCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setKick = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    9,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearKick = function () {
+  return this.setKick(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasKick = function () {
+  return Message.getField(this, 9) != null;
+};
+
+/**
+ * optional UpdateSync update_sync = 10;
+ * @return {?proto.UpdateSync}
+ */
+proto.ServerMessage.prototype.getUpdateSync = function () {
+  return /** @type{?proto.UpdateSync} */ Message.getWrapperField(
+    this,
+    proto.UpdateSync,
+    10,
+  );
+};
+
+/**
+ * @param {?proto.UpdateSync|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setUpdateSync = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    10,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearUpdateSync = function () {
+  return this.setUpdateSync(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasUpdateSync = function () {
+  return Message.getField(this, 10) != null;
+};
+
+/**
+ * optional ErrorCast error = 11;
+ * @return {?proto.ErrorCast}
+ */
+proto.ServerMessage.prototype.getError = function () {
+  return /** @type{?proto.ErrorCast} */ Message.getWrapperField(
+    this,
+    proto.ErrorCast,
+    11,
+  );
+};
+
+/**
+ * @param {?proto.ErrorCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setError = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    11,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearError = function () {
+  return this.setError(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ServerMessage.prototype.hasError = function () {
+  return Message.getField(this, 11) != null;
+};
+
+/**
+ * optional GameSettings game_settings = 12;
+ * @return {?proto.GameSettings}
+ */
+proto.ServerMessage.prototype.getGameSettings = function () {
+  return /** @type{?proto.GameSettings} */ Message.getWrapperField(
+    this,
+    proto.GameSettings,
+    12,
+  );
+};
+
+/**
+ * @param {?proto.GameSettings|undefined} value
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.setGameSettings = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    12,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
+};
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
+ */
+proto.ServerMessage.prototype.clearGameSettings = function () {
+  return this.setGameSettings(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.Duck.prototype.setId = function (value) {
-  return Message.setProto3IntField(this, 1, value);
+proto.ServerMessage.prototype.hasGameSettings = function () {
+  return Message.getField(this, 12) != null;
 };
 
 /**
- * optional float rotation = 2;
- * @return {number}
+ * optional HostCast host = 13;
+ * @return {?proto.HostCast}
  */
-proto.Duck.prototype.getRotation = function () {
-  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+proto.ServerMessage.prototype.getHost = function () {
+  return /** @type{?proto.HostCast} */ Message.getWrapperField(
     this,
-    2,
-    0.0,
+    proto.HostCast,
+    13,
   );
 };
 
 /**
- * @param {number} value
- * @return {!proto.Duck} returns this
+ * @param {?proto.HostCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
  */
-proto.Duck.prototype.setRotation = function (value) {
-  return Message.setProto3FloatField(this, 2, value);
+proto.ServerMessage.prototype.setHost = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    13,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
 };
 
 /**
- * optional float x = 3;
- * @return {number}
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
  */
-proto.Duck.prototype.getX = function () {
-  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
-    this,
-    3,
-    0.0,
-  );
+proto.ServerMessage.prototype.clearHost = function () {
+  return this.setHost(undefined);
 };
 
 /**
- * @param {number} value
- * @return {!proto.Duck} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.Duck.prototype.setX = function (value) {
-  return Message.setProto3FloatField(this, 3, value);
+proto.ServerMessage.prototype.hasHost = function () {
+  return Message.getField(this, 13) != null;
 };
 
 /**
- * optional float y = 4;
- * @return {number}
+ * optional PhaseCast phase = 14;
+ * @return {?proto.PhaseCast}
  */
-proto.Duck.prototype.getY = function () {
-  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+proto.ServerMessage.prototype.getPhase = function () {
+  return /** @type{?proto.PhaseCast} */ Message.getWrapperField(
     this,
-    4,
-    0.0,
+    proto.PhaseCast,
+    14,
   );
 };
 
 /**
- * @param {number} value
- * @return {!proto.Duck} returns this
+ * @param {?proto.PhaseCast|undefined} value
+ * @return {!proto.ServerMessage} returns this
  */
-proto.Duck.prototype.setY = function (value) {
-  return Message.setProto3FloatField(this, 4, value);
+proto.ServerMessage.prototype.setPhase = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    14,
+    proto.ServerMessage.oneofGroups_[0],
+    value,
+  );
 };
 
 /**
- * optional float z = 5;
- * @return {number}
+ * Clears the message field making it undefined.
+ * @return {!proto.ServerMessage} returns this
  */
-proto.Duck.prototype.getZ = function () {
-  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
-    this,
-    5,
-    0.0,
-  );
+proto.ServerMessage.prototype.clearPhase = function () {
+  return this.setPhase(undefined);
 };
 
 /**
- * @param {number} value
- * @return {!proto.Duck} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.Duck.prototype.setZ = function (value) {
-  return Message.setProto3FloatField(this, 5, value);
+proto.ServerMessage.prototype.hasPhase = function () {
+  return Message.getField(this, 14) != null;
 };
 
 /**
- * optional uint32 score = 6;
- * @return {number}
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
  */
-proto.Duck.prototype.getScore = function () {
-  return /** @type {number} */ Message.getFieldWithDefault(this, 6, 0);
-};
+proto.ReplayEvent.oneofGroups_ = [[2, 3, 4, 5, 6]];
 
 /**
- * @param {number} value
- * @return {!proto.Duck} returns this
+ * @enum {number}
  */
-proto.Duck.prototype.setScore = function (value) {
-  return Message.setProto3IntField(this, 6, value);
+proto.ReplayEvent.EventCase = {
+  EVENT_NOT_SET: 0,
+  UPDATE_SYNC: 2,
+  JOIN_GAME: 3,
+  LEAVE_GAME: 4,
+  START_GAME: 5,
+  END_GAME: 6,
 };
 
 /**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
+ * @return {proto.ReplayEvent.EventCase}
  */
-proto.UpdateSync.repeatedFields_ = [1];
+proto.ReplayEvent.prototype.getEventCase = function () {
+  return /** @type {proto.ReplayEvent.EventCase} */ Message.computeOneofCase(
+    this,
+    proto.ReplayEvent.oneofGroups_[0],
+  );
+};
 
 if (Message.GENERATE_TO_OBJECT) {
   /**
@@ -353,8 +8559,8 @@ if (Message.GENERATE_TO_OBJECT) {
    *     http://goto/soy-param-migration
    * @return {!Object}
    */
-  proto.UpdateSync.prototype.toObject = function (opt_includeInstance) {
-    return proto.UpdateSync.toObject(opt_includeInstance, this);
+  proto.ReplayEvent.prototype.toObject = function (opt_includeInstance) {
+    return proto.ReplayEvent.toObject(opt_includeInstance, this);
   };
 
   /**
@@ -362,21 +8568,29 @@ if (Message.GENERATE_TO_OBJECT) {
    * @param {boolean|undefined} includeInstance Deprecated. Whether to include
    *     the JSPB instance for transitional soy proto support:
    *     http://goto/soy-param-migration
-   * @param {!proto.UpdateSync} msg The msg instance to transform.
+   * @param {!proto.ReplayEvent} msg The msg instance to transform.
    * @return {!Object}
    * @suppress {unusedLocalVariables} f is only used for nested messages
    */
-  proto.UpdateSync.toObject = function (includeInstance, msg) {
+  proto.ReplayEvent.toObject = function (includeInstance, msg) {
     var f,
       obj = {
-        ducksList: Message.toObjectList(
-          msg.getDucksList(),
-          proto.Duck.toObject,
-          includeInstance,
-        ),
-        breadX: Message.getFloatingPointFieldWithDefault(msg, 2, 0.0),
-        breadY: Message.getFloatingPointFieldWithDefault(msg, 3, 0.0),
-        breadZ: Message.getFloatingPointFieldWithDefault(msg, 4, 0.0),
+        time: Message.getFieldWithDefault(msg, 1, 0),
+        updateSync:
+          (f = msg.getUpdateSync()) &&
+          proto.UpdateSync.toObject(includeInstance, f),
+        joinGame:
+          (f = msg.getJoinGame()) &&
+          proto.JoinGameCast.toObject(includeInstance, f),
+        leaveGame:
+          (f = msg.getLeaveGame()) &&
+          proto.LeaveGameCast.toObject(includeInstance, f),
+        startGame:
+          (f = msg.getStartGame()) &&
+          proto.StartGameCast.toObject(includeInstance, f),
+        endGame:
+          (f = msg.getEndGame()) &&
+          proto.EndGameCast.toObject(includeInstance, f),
       };
 
     if (includeInstance) {
@@ -389,22 +8603,22 @@ if (Message.GENERATE_TO_OBJECT) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.UpdateSync}
+ * @return {!proto.ReplayEvent}
  */
-proto.UpdateSync.deserializeBinary = function (bytes) {
+proto.ReplayEvent.deserializeBinary = function (bytes) {
   var reader = new BinaryReader(bytes);
-  var msg = new proto.UpdateSync();
-  return proto.UpdateSync.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.ReplayEvent();
+  return proto.ReplayEvent.deserializeBinaryFromReader(msg, reader);
 };
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.UpdateSync} msg The message object to deserialize into.
+ * @param {!proto.ReplayEvent} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.UpdateSync}
+ * @return {!proto.ReplayEvent}
  */
-proto.UpdateSync.deserializeBinaryFromReader = function (msg, reader) {
+proto.ReplayEvent.deserializeBinaryFromReader = function (msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -412,21 +8626,45 @@ proto.UpdateSync.deserializeBinaryFromReader = function (msg, reader) {
     var field = reader.getFieldNumber();
     switch (field) {
       case 1:
-        var value = new proto.Duck();
-        reader.readMessage(value, proto.Duck.deserializeBinaryFromReader);
-        msg.addDucks(value);
+        var value = /** @type {number} */ reader.readUint64();
+        msg.setTime(value);
         break;
       case 2:
-        var value = /** @type {number} */ reader.readFloat();
-        msg.setBreadX(value);
+        var value = new proto.UpdateSync();
+        reader.readMessage(value, proto.UpdateSync.deserializeBinaryFromReader);
+        msg.setUpdateSync(value);
         break;
       case 3:
-        var value = /** @type {number} */ reader.readFloat();
-        msg.setBreadY(value);
+        var value = new proto.JoinGameCast();
+        reader.readMessage(
+          value,
+          proto.JoinGameCast.deserializeBinaryFromReader,
+        );
+        msg.setJoinGame(value);
         break;
       case 4:
-        var value = /** @type {number} */ reader.readFloat();
-        msg.setBreadZ(value);
+        var value = new proto.LeaveGameCast();
+        reader.readMessage(
+          value,
+          proto.LeaveGameCast.deserializeBinaryFromReader,
+        );
+        msg.setLeaveGame(value);
+        break;
+      case 5:
+        var value = new proto.StartGameCast();
+        reader.readMessage(
+          value,
+          proto.StartGameCast.deserializeBinaryFromReader,
+        );
+        msg.setStartGame(value);
+        break;
+      case 6:
+        var value = new proto.EndGameCast();
+        reader.readMessage(
+          value,
+          proto.EndGameCast.deserializeBinaryFromReader,
+        );
+        msg.setEndGame(value);
         break;
       default:
         reader.skipField();
@@ -440,188 +8678,295 @@ proto.UpdateSync.deserializeBinaryFromReader = function (msg, reader) {
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.UpdateSync.prototype.serializeBinary = function () {
+proto.ReplayEvent.prototype.serializeBinary = function () {
   var writer = new BinaryWriter();
-  proto.UpdateSync.serializeBinaryToWriter(this, writer);
+  proto.ReplayEvent.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.UpdateSync} message
+ * @param {!proto.ReplayEvent} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.UpdateSync.serializeBinaryToWriter = function (message, writer) {
+proto.ReplayEvent.serializeBinaryToWriter = function (message, writer) {
   var f = undefined;
-  f = message.getDucksList();
-  if (f.length > 0) {
-    writer.writeRepeatedMessage(1, f, proto.Duck.serializeBinaryToWriter);
+  f = message.getTime();
+  if (f !== 0) {
+    writer.writeUint64(1, f);
   }
-  f = /** @type {number} */ Message.getField(message, 2);
+  f = message.getUpdateSync();
   if (f != null) {
-    writer.writeFloat(2, f);
+    writer.writeMessage(2, f, proto.UpdateSync.serializeBinaryToWriter);
   }
-  f = /** @type {number} */ Message.getField(message, 3);
+  f = message.getJoinGame();
   if (f != null) {
-    writer.writeFloat(3, f);
+    writer.writeMessage(3, f, proto.JoinGameCast.serializeBinaryToWriter);
   }
-  f = /** @type {number} */ Message.getField(message, 4);
+  f = message.getLeaveGame();
   if (f != null) {
-    writer.writeFloat(4, f);
+    writer.writeMessage(4, f, proto.LeaveGameCast.serializeBinaryToWriter);
+  }
+  f = message.getStartGame();
+  if (f != null) {
+    writer.writeMessage(5, f, proto.StartGameCast.serializeBinaryToWriter);
+  }
+  f = message.getEndGame();
+  if (f != null) {
+    writer.writeMessage(6, f, proto.EndGameCast.serializeBinaryToWriter);
   }
 };
 
 /**
- * repeated Duck ducks = 1;
- * @return {!Array<!proto.Duck>}
+ * optional uint64 time = 1;
+ * @return {number}
  */
-proto.UpdateSync.prototype.getDucksList = function () {
-  return /** @type{!Array<!proto.Duck>} */ Message.getRepeatedWrapperField(
+proto.ReplayEvent.prototype.getTime = function () {
+  return /** @type {number} */ Message.getFieldWithDefault(this, 1, 0);
+};
+
+/**
+ * @param {number} value
+ * @return {!proto.ReplayEvent} returns this
+ */
+proto.ReplayEvent.prototype.setTime = function (value) {
+  return Message.setProto3IntField(this, 1, value);
+};
+
+/**
+ * optional UpdateSync update_sync = 2;
+ * @return {?proto.UpdateSync}
+ */
+proto.ReplayEvent.prototype.getUpdateSync = function () {
+  return /** @type{?proto.UpdateSync} */ Message.getWrapperField(
     this,
-    proto.Duck,
-    1,
+    proto.UpdateSync,
+    2,
   );
 };
 
 /**
- * @param {!Array<!proto.Duck>} value
- * @return {!proto.UpdateSync} returns this
+ * @param {?proto.UpdateSync|undefined} value
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.setDucksList = function (value) {
-  return Message.setRepeatedWrapperField(this, 1, value);
+proto.ReplayEvent.prototype.setUpdateSync = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    2,
+    proto.ReplayEvent.oneofGroups_[0],
+    value,
+  );
 };
 
 /**
- * @param {!proto.Duck=} opt_value
- * @param {number=} opt_index
- * @return {!proto.Duck}
+ * Clears the message field making it undefined.
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.addDucks = function (opt_value, opt_index) {
-  return Message.addToRepeatedWrapperField(
+proto.ReplayEvent.prototype.clearUpdateSync = function () {
+  return this.setUpdateSync(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ReplayEvent.prototype.hasUpdateSync = function () {
+  return Message.getField(this, 2) != null;
+};
+
+/**
+ * optional JoinGameCast join_game = 3;
+ * @return {?proto.JoinGameCast}
+ */
+proto.ReplayEvent.prototype.getJoinGame = function () {
+  return /** @type{?proto.JoinGameCast} */ Message.getWrapperField(
     this,
-    1,
-    opt_value,
-    proto.Duck,
-    opt_index,
+    proto.JoinGameCast,
+    3,
   );
 };
 
 /**
- * Clears the list making it empty but non-null.
- * @return {!proto.UpdateSync} returns this
+ * @param {?proto.JoinGameCast|undefined} value
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.clearDucksList = function () {
-  return this.setDucksList([]);
+proto.ReplayEvent.prototype.setJoinGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    3,
+    proto.ReplayEvent.oneofGroups_[0],
+    value,
+  );
 };
 
 /**
- * optional float bread_x = 2;
- * @return {number}
+ * Clears the message field making it undefined.
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.getBreadX = function () {
-  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+proto.ReplayEvent.prototype.clearJoinGame = function () {
+  return this.setJoinGame(undefined);
+};
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.ReplayEvent.prototype.hasJoinGame = function () {
+  return Message.getField(this, 3) != null;
+};
+
+/**
+ * optional LeaveGameCast leave_game = 4;
+ * @return {?proto.LeaveGameCast}
+ */
+proto.ReplayEvent.prototype.getLeaveGame = function () {
+  return /** @type{?proto.LeaveGameCast} */ Message.getWrapperField(
     this,
-    2,
-    0.0,
+    proto.LeaveGameCast,
+    4,
   );
 };
 
 /**
- * @param {number} value
- * @return {!proto.UpdateSync} returns this
+ * @param {?proto.LeaveGameCast|undefined} value
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.setBreadX = function (value) {
-  return Message.setField(this, 2, value);
+proto.ReplayEvent.prototype.setLeaveGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    4,
+    proto.ReplayEvent.oneofGroups_[0],
+    value,
+  );
 };
 
 /**
- * Clears the field making it undefined.
- * @return {!proto.UpdateSync} returns this
+ * Clears the message field making it undefined.
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.clearBreadX = function () {
-  return Message.setField(this, 2, undefined);
+proto.ReplayEvent.prototype.clearLeaveGame = function () {
+  return this.setLeaveGame(undefined);
 };
 
 /**
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.UpdateSync.prototype.hasBreadX = function () {
-  return Message.getField(this, 2) != null;
+proto.ReplayEvent.prototype.hasLeaveGame = function () {
+  return Message.getField(this, 4) != null;
 };
 
 /**
- * optional float bread_y = 3;
- * @return {number}
+ * optional StartGameCast start_game = 5;
+ * @return {?proto.StartGameCast}
  */
-proto.UpdateSync.prototype.getBreadY = function () {
-  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+proto.ReplayEvent.prototype.getStartGame = function () {
+  return /** @type{?proto.StartGameCast} */ Message.getWrapperField(
     this,
-    3,
-    0.0,
+    proto.StartGameCast,
+    5,
   );
 };
 
 /**
- * @param {number} value
- * @return {!proto.UpdateSync} returns this
+ * @param {?proto.StartGameCast|undefined} value
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.setBreadY = function (value) {
-  return Message.setField(this, 3, value);
+proto.ReplayEvent.prototype.setStartGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    5,
+    proto.ReplayEvent.oneofGroups_[0],
+    value,
+  );
 };
 
 /**
- * Clears the field making it undefined.
- * @return {!proto.UpdateSync} returns this
+ * Clears the message field making it undefined.
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.clearBreadY = function () {
-  return Message.setField(this, 3, undefined);
+proto.ReplayEvent.prototype.clearStartGame = function () {
+  return this.setStartGame(undefined);
 };
 
 /**
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.UpdateSync.prototype.hasBreadY = function () {
-  return Message.getField(this, 3) != null;
+proto.ReplayEvent.prototype.hasStartGame = function () {
+  return Message.getField(this, 5) != null;
 };
 
 /**
- * optional float bread_z = 4;
- * @return {number}
+ * optional EndGameCast end_game = 6;
+ * @return {?proto.EndGameCast}
  */
-proto.UpdateSync.prototype.getBreadZ = function () {
-  return /** @type {number} */ Message.getFloatingPointFieldWithDefault(
+proto.ReplayEvent.prototype.getEndGame = function () {
+  return /** @type{?proto.EndGameCast} */ Message.getWrapperField(
     this,
-    4,
-    0.0,
+    proto.EndGameCast,
+    6,
   );
 };
 
 /**
- * @param {number} value
- * @return {!proto.UpdateSync} returns this
+ * @param {?proto.EndGameCast|undefined} value
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.setBreadZ = function (value) {
-  return Message.setField(this, 4, value);
+proto.ReplayEvent.prototype.setEndGame = function (value) {
+  return Message.setOneofWrapperField(
+    this,
+    6,
+    proto.ReplayEvent.oneofGroups_[0],
+    value,
+  );
 };
 
 /**
- * Clears the field making it undefined.
- * @return {!proto.UpdateSync} returns this
+ * Clears the message field making it undefined.
+ * @return {!proto.ReplayEvent} returns this
  */
-proto.UpdateSync.prototype.clearBreadZ = function () {
-  return Message.setField(this, 4, undefined);
+proto.ReplayEvent.prototype.clearEndGame = function () {
+  return this.setEndGame(undefined);
 };
 
 /**
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.UpdateSync.prototype.hasBreadZ = function () {
-  return Message.getField(this, 4) != null;
+proto.ReplayEvent.prototype.hasEndGame = function () {
+  return Message.getField(this, 6) != null;
+};
+
+/**
+ * @enum {number}
+ */
+proto.BreadKind = {
+  CRUST: 0,
+  GOLDEN_LOAF: 1,
+  STALE: 2,
+};
+
+/**
+ * @enum {number}
+ */
+proto.PowerUpKind = {
+  SPEED: 0,
+  MAGNET: 1,
+  DOUBLE_POINTS: 2,
+  SHIELD: 3,
+};
+
+/**
+ * @enum {number}
+ */
+proto.Phase = {
+  WAITING: 0,
+  COUNTDOWN: 1,
+  PLAYING: 2,
+  PODIUM: 3,
 };
 
 // goog.object.extend(exports, proto);
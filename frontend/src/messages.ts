@@ -15,6 +15,13 @@ export function voteStartGameMessage() {
   return `vote_start_game`;
 }
 
+/**
+ * Message sent to backend, indicates this duck retracted their vote to start game
+ */
+export function retractVoteStartGameMessage() {
+  return `retract_vote_start_game`;
+}
+
 /**
  * Binary message sent to backend, indicates this duck's current world state
  */
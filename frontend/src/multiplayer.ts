@@ -3,7 +3,16 @@ import Duck from "./objects/duck";
 import Protos from "../protos_pb";
 import Bread from "./objects/bread";
 import { GameMode } from "./options";
-import { binaryUpdateMessage, joinGameMessage } from "./messages";
+import {
+  binaryUpdateMessage,
+  joinGameMessage,
+  retractVoteStartGameMessage,
+  voteStartGameMessage,
+} from "./messages";
+
+// whether this client voted to start, reset when the server clears votes
+// on joins, leaves, settings changes and phase changes
+let ready = false;
 
 /**
  * Connects to backend and adds event listeners to handle incoming messages
@@ -12,7 +21,9 @@ export default function serverConnect(game: Game) {
   var socket: WebSocket | null = null;
 
   const protocol = location.protocol.startsWith("https") ? "wss" : "ws";
-  const wsUri = `${protocol}://${location.hostname}:4421/ws`;
+  const lobby = new URLSearchParams(location.search).get("lobby");
+  const lobbyQuery = lobby ? `?lobby=${encodeURIComponent(lobby)}` : "";
+  const wsUri = `${protocol}://${location.hostname}:4421/ws${lobbyQuery}`;
 
   socket = new WebSocket(wsUri);
 
@@ -52,6 +63,19 @@ function handleOpen(socket: WebSocket | null, game: Game) {
 
   socket.send(joinGameMessage(game.ducks[0]));
 
+  // enter toggles voting to start while waiting
+  window.addEventListener("keydown", (event) => {
+    if (!socket || event.key !== "Enter" || event.repeat) {
+      return;
+    }
+    if (game.gameMode !== GameMode.WAITING) {
+      ready = false;
+      return;
+    }
+    ready = !ready;
+    socket.send(ready ? voteStartGameMessage() : retractVoteStartGameMessage());
+  });
+
   setInterval(() => {
     if (socket) {
       socket.send(binaryUpdateMessage(game.ducks[0]));
@@ -81,6 +105,11 @@ function handleStringMessage(message: MessageEvent, game: Game) {
       game.gameDuration = parseInt(data[2]);
       game.gameMode = GameMode.ONLINE;
 
+      // spectators are promoted to players when a round starts
+      for (const duck of game.ducks) {
+        duck.visible = true;
+      }
+
       document.getElementById("timer")!.innerText = "02:00";
       break;
 
@@ -100,10 +129,11 @@ function handleStringMessage(message: MessageEvent, game: Game) {
       break;
 
     case "cast:join_game":
-      // id name variety color
+      // id name variety color spectator host
       game.ducks.push(new Duck(data[2], parseInt(data[3]), data[4]));
       game.ducks[game.ducks.length - 1].duckId = data[1];
       game.ducks[game.ducks.length - 1].nameText.visible = true;
+      game.ducks[game.ducks.length - 1].visible = data[5] !== "1";
       game.scene.add(game.ducks[game.ducks.length - 1]);
       break;
 
@@ -117,6 +147,22 @@ function handleStringMessage(message: MessageEvent, game: Game) {
       document.getElementById("timer")!.innerText = "02:00";
       break;
 
+    case "cast:vote_status":
+      // votes ducks required_votes
+      if (data[1] === "0") {
+        ready = false;
+      }
+      if (game.gameMode === GameMode.WAITING) {
+        document.getElementById("timer")!.innerText =
+          `Waiting to start.. (${data[1]}/${data[2]} ready, ${data[3]} needed, press enter to toggle ready)`;
+      }
+      break;
+
+    case "cast:phase":
+      // phase remaining_ms
+      ready = false;
+      break;
+
     case "cast:leave_game":
       const leave_id = data[1];
 